@@ -0,0 +1,166 @@
+use std::{
+    collections::{BTreeMap, btree_map},
+    fmt::{Debug, Formatter},
+    ops::RangeBounds,
+};
+
+use crate::{DefaultIdType, Id};
+
+/// `BTreeMap` keyed by IDs sharing an owner type T, iterating in ID order
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, TypedIdBTreeMap};
+/// let mut names = TypedIdBTreeMap::<String, &str>::new();
+/// names.insert(Id::new(2), "bob");
+/// names.insert(Id::new(1), "alice");
+/// assert_eq!(names.keys().copied().collect::<Vec<_>>(), [Id::new(1), Id::new(2)]);
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, TypedIdBTreeMap};
+/// let mut names = TypedIdBTreeMap::<String, &str>::new();
+/// names.insert(Id::<&str>::new(1), "alice"); // wrong owner type
+/// ```
+pub struct TypedIdBTreeMap<T, V, I = DefaultIdType> {
+    map: BTreeMap<Id<T, I>, V>,
+}
+
+impl<T, V, I> TypedIdBTreeMap<T, V, I> {
+    pub fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, Id<T, I>, V> {
+        self.map.iter()
+    }
+
+    pub fn keys(&self) -> btree_map::Keys<'_, Id<T, I>, V> {
+        self.map.keys()
+    }
+
+    pub fn values(&self) -> btree_map::Values<'_, Id<T, I>, V> {
+        self.map.values()
+    }
+}
+
+impl<T, V, I: Ord> TypedIdBTreeMap<T, V, I> {
+    /// Returns the previous value for the ID, if any
+    pub fn insert(&mut self, id: Id<T, I>, value: V) -> Option<V> {
+        self.map.insert(id, value)
+    }
+
+    pub fn get(&self, id: &Id<T, I>) -> Option<&V> {
+        self.map.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: &Id<T, I>) -> Option<&mut V> {
+        self.map.get_mut(id)
+    }
+
+    pub fn contains_key(&self, id: &Id<T, I>) -> bool {
+        self.map.contains_key(id)
+    }
+
+    pub fn remove(&mut self, id: &Id<T, I>) -> Option<V> {
+        self.map.remove(id)
+    }
+
+    /// Iterates over the entries with IDs in `range`, in ID order
+    ///
+    /// # Panics
+    ///
+    /// If the range's start is greater than its end, like [`BTreeMap::range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, TypedIdBTreeMap};
+    /// let map: TypedIdBTreeMap<String, char> =
+    ///     [(Id::new(1), 'a'), (Id::new(5), 'e'), (Id::new(9), 'i')].into_iter().collect();
+    /// let hits: Vec<_> = map.range(Id::new(2)..Id::new(9)).collect();
+    /// assert_eq!(hits, [(&Id::new(5), &'e')]);
+    /// ```
+    pub fn range<R: RangeBounds<Id<T, I>>>(&self, range: R) -> btree_map::Range<'_, Id<T, I>, V> {
+        self.map.range(range)
+    }
+
+    pub fn first_key_value(&self) -> Option<(&Id<T, I>, &V)> {
+        self.map.first_key_value()
+    }
+
+    pub fn last_key_value(&self) -> Option<(&Id<T, I>, &V)> {
+        self.map.last_key_value()
+    }
+}
+
+impl<T, V, I> Default for TypedIdBTreeMap<T, V, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: Debug, I: Debug> Debug for TypedIdBTreeMap<T, V, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+impl<T, V: Clone, I: Clone> Clone for TypedIdBTreeMap<T, V, I> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, V: PartialEq, I: PartialEq> PartialEq for TypedIdBTreeMap<T, V, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T, V: Eq, I: Eq> Eq for TypedIdBTreeMap<T, V, I> {}
+
+impl<T, V, I: Ord> FromIterator<(Id<T, I>, V)> for TypedIdBTreeMap<T, V, I> {
+    fn from_iter<It: IntoIterator<Item = (Id<T, I>, V)>>(iter: It) -> Self {
+        Self {
+            map: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, V, I: Ord> Extend<(Id<T, I>, V)> for TypedIdBTreeMap<T, V, I> {
+    fn extend<It: IntoIterator<Item = (Id<T, I>, V)>>(&mut self, iter: It) {
+        self.map.extend(iter);
+    }
+}
+
+impl<T, V, I> IntoIterator for TypedIdBTreeMap<T, V, I> {
+    type Item = (Id<T, I>, V);
+    type IntoIter = btree_map::IntoIter<Id<T, I>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, T, V, I> IntoIterator for &'a TypedIdBTreeMap<T, V, I> {
+    type Item = (&'a Id<T, I>, &'a V);
+    type IntoIter = btree_map::Iter<'a, Id<T, I>, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Id;
+
+macro_rules! from_be_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            /// Reads the backing from big-endian (network order) bytes
+            ///
+            /// Use `Id::new(<int>::from_le_bytes(bytes))` for little-endian fields.
+            impl<T> From<[u8; size_of::<$t>()]> for Id<T, $t> {
+                fn from(bytes: [u8; size_of::<$t>()]) -> Self {
+                    Id::new(<$t>::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+from_be_bytes! { u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
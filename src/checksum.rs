@@ -0,0 +1,82 @@
+use std::any::type_name;
+
+use crate::{Id, ParseIdError, ParseIdErrorKind, UnsignedBacking};
+
+/// Luhn sum of `digits`, doubling every other digit starting from the last when `double_last`
+fn luhn_sum(digits: impl DoubleEndedIterator<Item = u32>, double_last: bool) -> u32 {
+    digits
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            if (i % 2 == 0) == double_last {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum()
+}
+
+impl<T, I: UnsignedBacking> Id<T, I> {
+    /// Displays the ID in decimal followed by a Luhn check digit
+    ///
+    /// The check digit catches any single mistyped digit & most swaps of adjacent digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u64>::new(7992739871).to_string_checked(), "79927398713");
+    /// ```
+    pub fn to_string_checked(&self) -> String {
+        let mut s = self.id.to_u128().to_string();
+        let sum = luhn_sum(s.bytes().map(|b| u32::from(b - b'0')), true);
+        let check = (10 - sum % 10) % 10;
+        s.push(char::from_digit(check, 10).expect("check digit is below 10"));
+        s
+    }
+
+    /// Parses a string produced by [`Id::to_string_checked`], validating & stripping its check
+    /// digit
+    ///
+    /// Leading zeros are rejected, so each ID has exactly one checked form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, ParseIdErrorKind};
+    /// assert_eq!(Id::<String, u64>::from_str_checked("79927398713"), Ok(Id::new(7992739871)));
+    ///
+    /// let err = Id::<String, u64>::from_str_checked("79927398710").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseIdErrorKind::ChecksumMismatch);
+    /// ```
+    pub fn from_str_checked(s: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        if s.is_empty() {
+            return Err(error(ParseIdErrorKind::Empty));
+        }
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(error(ParseIdErrorKind::InvalidDigit(c)));
+        }
+        if s.len() < 2 {
+            return Err(error(ParseIdErrorKind::Malformed));
+        }
+
+        let digits = s.bytes().map(|b| u32::from(b - b'0'));
+        if luhn_sum(digits, false) % 10 != 0 {
+            return Err(error(ParseIdErrorKind::ChecksumMismatch));
+        }
+
+        let payload = &s[..s.len() - 1];
+        if payload.len() > 1 && payload.starts_with('0') {
+            return Err(error(ParseIdErrorKind::Malformed));
+        }
+        payload
+            .parse::<u128>()
+            .ok()
+            .and_then(I::from_u128)
+            .map(Id::new)
+            .ok_or_else(|| error(ParseIdErrorKind::Overflow))
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Source of the current time for time-based ID generators
+pub trait Clock {
+    /// Milliseconds since the Unix epoch
+    fn now_ms(&self) -> u64;
+}
+
+/// The system's wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    /// # Panics
+    ///
+    /// If the system clock is set before the Unix epoch.
+    fn now_ms(&self) -> u64 {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is before the Unix epoch");
+        elapsed.as_millis().try_into().unwrap_or(u64::MAX)
+    }
+}
+
+/// Manually driven clock for deterministic tests of time-based IDs
+///
+/// Clones share the same time, so a test can keep one to drive a clock moved into a generator.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Clock, MockClock};
+/// let clock = MockClock::new(1_000);
+/// let handle = clock.clone();
+/// handle.advance_ms(5);
+/// assert_eq!(clock.now_ms(), 1_005);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now_ms: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    pub fn new(now_ms: u64) -> Self {
+        Self {
+            now_ms: Arc::new(AtomicU64::new(now_ms)),
+        }
+    }
+
+    pub fn set_ms(&self, now_ms: u64) {
+        self.now_ms.store(now_ms, Ordering::Relaxed);
+    }
+
+    pub fn advance_ms(&self, ms: u64) {
+        self.now_ms.fetch_add(ms, Ordering::Relaxed);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms.load(Ordering::Relaxed)
+    }
+}
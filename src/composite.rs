@@ -0,0 +1,111 @@
+use std::{
+    any::type_name,
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use crate::{ParseIdError, ParseIdErrorKind};
+
+/// Pair of IDs keying a join table, like a user's membership of a group
+///
+/// Displays as `{a}:{b}` & parses back by splitting on the first `:`. Any `:` or `%` in the
+/// first ID is percent-escaped, so every pair round-trips. Each half keeps its own owner type,
+/// & a failure to parse one reports that half's owner. With the `serde` feature it serializes
+/// as the same string.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{CompositeId, Id};
+///
+/// struct User;
+/// struct Group;
+/// let key = CompositeId::new((Id::<User>::new(5), Id::<Group>::new(9)));
+/// assert_eq!(key.to_string(), "5:9");
+/// assert_eq!("5:9".parse(), Ok(key));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompositeId<C> {
+    parts: C,
+}
+
+impl<C> CompositeId<C> {
+    pub fn new(parts: C) -> Self {
+        Self { parts }
+    }
+
+    pub fn parts(&self) -> &C {
+        &self.parts
+    }
+
+    pub fn into_parts(self) -> C {
+        self.parts
+    }
+}
+
+impl<A, B> From<(A, B)> for CompositeId<(A, B)> {
+    fn from(parts: (A, B)) -> Self {
+        Self::new(parts)
+    }
+}
+
+impl<A: Display, B: Display> Display for CompositeId<(A, B)> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(EscapeSeparator(f), "{}", self.parts.0)?;
+        write!(f, ":{}", self.parts.1)
+    }
+}
+
+/// Writes through to `Formatter`, percent-escaping `:` & `%`
+pub(crate) struct EscapeSeparator<'a, 'b>(pub(crate) &'a mut Formatter<'b>);
+
+impl Write for EscapeSeparator<'_, '_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        for part in s.split_inclusive([':', '%']) {
+            match part.strip_suffix(':') {
+                Some(before) => write!(self.0, "{before}%3A")?,
+                None => match part.strip_suffix('%') {
+                    Some(before) => write!(self.0, "{before}%25")?,
+                    None => self.0.write_str(part)?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reverses [`EscapeSeparator`], or `None` on any other escape
+pub(crate) fn unescape_separator(s: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut parts = s.split('%');
+    unescaped.push_str(parts.next()?);
+    for part in parts {
+        let rest = if let Some(rest) = part.strip_prefix("3A") {
+            unescaped.push(':');
+            rest
+        } else {
+            unescaped.push('%');
+            part.strip_prefix("25")?
+        };
+        unescaped.push_str(rest);
+    }
+    Some(unescaped)
+}
+
+impl<A, B> FromStr for CompositeId<(A, B)>
+where
+    A: FromStr<Err = ParseIdError>,
+    B: FromStr<Err = ParseIdError>,
+{
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |kind| ParseIdError::new(s, type_name::<Self>(), kind);
+        let (a, b) = s
+            .split_once(':')
+            .ok_or_else(|| error(ParseIdErrorKind::MissingSeparator(':')))?;
+        let a = unescape_separator(a).ok_or_else(|| error(ParseIdErrorKind::Malformed))?;
+
+        Ok(Self::new((a.parse()?, b.parse()?)))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::Id;
+
+/// Marks converting IDs of owner `From` into IDs of owner `To` as meaningful
+///
+/// Implemented on `From` itself, this allows [`Id::convert_owner`] between exactly the listed
+/// owner pairs, unlike [`Id::change_owner_type`] which allows any.
+///
+/// ```
+/// use typed_id::{ConvertOwner, Id};
+///
+/// struct Draft;
+/// struct Published;
+/// impl ConvertOwner<Draft, Published> for Draft {}
+///
+/// let draft = Id::<Draft>::new(3);
+/// let published: Id<Published> = draft.convert_owner();
+/// assert_eq!(published, Id::new(3));
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{ConvertOwner, Id};
+///
+/// struct Draft;
+/// struct Published;
+/// impl ConvertOwner<Draft, Published> for Draft {}
+///
+/// let published = Id::<Published>::new(3);
+/// let draft: Id<Draft> = published.convert_owner(); // only Draft -> Published is allowed
+/// ```
+pub trait ConvertOwner<From, To> {}
+
+impl<T, I> Id<T, I> {
+    /// Changes the owner type to T2, if T declares the conversion with [`ConvertOwner`]
+    pub fn convert_owner<T2>(self) -> Id<T2, I>
+    where
+        T: ConvertOwner<T, T2>,
+    {
+        Id::new(self.id)
+    }
+}
+
+/// Anything convertible into an `Id<T, I>`, for APIs taking either an ID or its raw backing
+///
+/// Implemented for every type that converts into the ID through `Into`, which includes the
+/// ID itself & its backing. The owner type still has to match.
+///
+/// ```
+/// use typed_id::{Id, IntoId};
+///
+/// struct User;
+/// fn fetch(id: impl IntoId<User, u32>) -> Id<User> {
+///     id.into_id()
+/// }
+///
+/// assert_eq!(fetch(5), Id::new(5));
+/// assert_eq!(fetch(Id::<User>::new(5)), Id::new(5));
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, IntoId};
+///
+/// struct User;
+/// fn fetch(id: impl IntoId<User, u32>) {}
+///
+/// fetch(Id::<String>::new(5)); // wrong owner type
+/// ```
+pub trait IntoId<T, I> {
+    fn into_id(self) -> Id<T, I>;
+}
+
+impl<T, I, X: Into<Id<T, I>>> IntoId<T, I> for X {
+    fn into_id(self) -> Id<T, I> {
+        self.into()
+    }
+}
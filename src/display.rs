@@ -0,0 +1,269 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Write},
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{FixedStr, Id, IntegerBacking};
+
+/// Backings that can be displayed as a string, borrowing when they already are one
+///
+/// See [`Id::as_display_str`].
+pub trait DisplayStr {
+    fn display_str(&self) -> Cow<'_, str>;
+}
+
+impl DisplayStr for str {
+    fn display_str(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+macro_rules! borrowed_display_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DisplayStr for $t {
+                fn display_str(&self) -> Cow<'_, str> {
+                    Cow::Borrowed(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! formatted_display_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DisplayStr for $t {
+                fn display_str(&self) -> Cow<'_, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+borrowed_display_str! { &str, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+formatted_display_str! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+
+impl<const N: usize> DisplayStr for FixedStr<N> {
+    fn display_str(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+impl<T, I: DisplayStr> Id<T, I> {
+    /// The displayed ID, borrowed from string backings instead of cloning like `to_string`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_id::Id;
+    /// let id = Id::<String, String>::new("usr_1".to_string());
+    /// assert!(matches!(id.as_display_str(), Cow::Borrowed("usr_1")));
+    /// assert_eq!(Id::<String>::new(5).as_display_str(), "5");
+    /// ```
+    pub fn as_display_str(&self) -> Cow<'_, str> {
+        self.id.display_str()
+    }
+}
+
+/// Displays an optional ID, or a placeholder when it's `None`, see [`display_or`]
+pub struct OptionIdDisplay<'a, T, I> {
+    id: Option<&'a Id<T, I>>,
+    placeholder: &'a str,
+}
+
+/// Displays `opt`'s ID if it has one, else `placeholder`
+///
+/// Formatter flags like width apply to either.
+///
+/// # Examples
+///
+/// ```
+/// use typed_id::{Id, display_or};
+/// let manager = Some(Id::<String>::new(5));
+/// assert_eq!(display_or(&manager, "-").to_string(), "5");
+/// assert_eq!(format!("[{:>3}]", display_or(&None::<Id<String>>, "-")), "[  -]");
+/// ```
+pub fn display_or<'a, T, I>(
+    opt: &'a Option<Id<T, I>>,
+    placeholder: &'a str,
+) -> OptionIdDisplay<'a, T, I> {
+    OptionIdDisplay {
+        id: opt.as_ref(),
+        placeholder,
+    }
+}
+
+impl<T, I: Display> Display for OptionIdDisplay<'_, T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.id {
+            Some(id) => Display::fmt(id, f),
+            None => f.pad(self.placeholder),
+        }
+    }
+}
+
+/// Displays a string ID cut to a maximum length, see [`Id::truncated`]
+pub struct TruncatedId<'a, T, I> {
+    id: &'a Id<T, I>,
+    max: usize,
+}
+
+impl<T, I: AsRef<str>> Id<T, I> {
+    /// Displays at most `max` characters of the ID followed by an ellipsis, for readable logs
+    ///
+    /// Only string backings are supported, as a truncated number reads as a different ID.
+    /// Width & alignment flags apply to the truncated output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, &str>::new("0123456789");
+    /// assert_eq!(id.truncated(4).to_string(), "0123…");
+    /// assert_eq!(id.truncated(10).to_string(), "0123456789");
+    /// assert_eq!(format!("[{:>6}]", id.truncated(4)), "[ 0123…]");
+    /// ```
+    pub fn truncated(&self, max: usize) -> TruncatedId<'_, T, I> {
+        TruncatedId { id: self, max }
+    }
+}
+
+impl<T, I: AsRef<str>> Display for TruncatedId<'_, T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let id = self.id.id.as_ref();
+        match id.char_indices().nth(self.max) {
+            Some((end, _)) => f.pad(&format!("{}…", &id[..end])),
+            None => f.pad(id),
+        }
+    }
+}
+
+/// Displays an integer ID wrapped in double quotes, see [`Id::quoted`]
+pub struct QuotedId<'a, T, I>(&'a Id<T, I>);
+
+impl<T, I: IntegerBacking> Id<T, I> {
+    /// Displays the ID as a quoted string, for log pipelines that ingest every field as a string
+    ///
+    /// Only integer backings are supported, since their output never needs escaping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(5);
+    /// assert_eq!(id.quoted().to_string(), r#""5""#);
+    /// assert_eq!(format!("user={}", id.quoted()), r#"user="5""#);
+    /// ```
+    pub fn quoted(&self) -> QuotedId<'_, T, I> {
+        QuotedId(self)
+    }
+}
+
+impl<T, I: IntegerBacking> Id<T, I> {
+    /// The ID with its digits grouped in threes by commas, for human-facing dashboards
+    ///
+    /// Display only & locale-neutral; the wire format stays ungrouped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::new(1_234_567).grouped_display(), "1,234,567");
+    /// assert_eq!(Id::<String, i64>::new(-1000).grouped_display(), "-1,000");
+    /// ```
+    pub fn grouped_display(&self) -> String {
+        let digits = self.id.to_string();
+        let (sign, digits) = match digits.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", digits.as_str()),
+        };
+
+        let mut grouped = String::from(sign);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+impl<T, I: IntegerBacking> Display for QuotedId<'_, T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\"", self.0.id)
+    }
+}
+
+impl<T, I: Display> Id<T, I> {
+    /// Writes the ID into `buf` without allocating an intermediate `String`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let mut line = String::from("user=");
+    /// Id::<String>::new(5).write_to(&mut line).unwrap();
+    /// assert_eq!(line, "user=5");
+    /// ```
+    pub fn write_to(&self, buf: &mut impl Write) -> std::fmt::Result {
+        write!(buf, "{}", self.id)
+    }
+
+    /// Renders the ID as `{ShortOwnerName}Id({value})`, for error messages
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// struct User;
+    /// assert_eq!(Id::<User>::new(5).qualified_display(), "UserId(5)");
+    /// ```
+    pub fn qualified_display(&self) -> String {
+        self.qualified().to_string()
+    }
+
+    /// Lazily displays the ID as `{ShortOwnerName}Id({value})`, see [`Id::qualified_display`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// struct User;
+    /// let id = Id::<User>::new(5);
+    /// assert_eq!(format!("no such user {}", id.qualified()), "no such user UserId(5)");
+    /// ```
+    pub fn qualified(&self) -> impl Display + '_ {
+        Qualified(self)
+    }
+}
+
+struct Qualified<'a, T, I>(&'a Id<T, I>);
+
+impl<T, I: Display> Display for Qualified<'_, T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}Id({})", self.0.short_owner_type(), self.0.id)
+    }
+}
+
+#[cfg(feature = "itoa")]
+impl<T, I: IntegerBacking + itoa::Integer> Id<T, I> {
+    /// Writes the ID's decimal digits into `out`, formatting on the stack with `itoa`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let mut out = Vec::new();
+    /// Id::<String, i64>::new(-42).write_bytes(&mut out).unwrap();
+    /// assert_eq!(out, b"-42");
+    /// ```
+    pub fn write_bytes(&self, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        out.write_all(itoa::Buffer::new().format(self.id).as_bytes())
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    any::{TypeId, type_name},
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::{DefaultIdType, Id};
+
+/// [`Id`] with its owner type erased to a runtime `TypeId`
+///
+/// Lets IDs of different owners share a collection, while still only converting back to an
+/// `Id` of the original owner.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{DynId, Id};
+/// let ids = [DynId::from(Id::<String>::new(1)), DynId::from(Id::<bool>::new(2))];
+///
+/// assert!(ids[0].is::<String>());
+/// assert_eq!(ids[1].clone().downcast::<bool>().ok(), Some(Id::new(2)));
+/// assert!(ids[1].clone().downcast::<String>().is_err());
+/// ```
+pub struct DynId<I = DefaultIdType> {
+    id: I,
+    owner: TypeId,
+    owner_name: &'static str,
+}
+
+impl<I> DynId<I> {
+    /// Whether the ID's owner type is T
+    pub fn is<T: 'static>(&self) -> bool {
+        self.owner == TypeId::of::<T>()
+    }
+
+    /// Converts back to an `Id` if its owner type is T, else returns the `DynId` unchanged
+    pub fn downcast<T: 'static>(self) -> Result<Id<T, I>, Self> {
+        if self.is::<T>() {
+            Ok(Id::new(self.id))
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn owner_type(&self) -> &'static str {
+        self.owner_name
+    }
+
+    pub fn owner_type_id(&self) -> TypeId {
+        self.owner
+    }
+
+    pub fn backing_type(&self) -> &'static str {
+        type_name::<I>()
+    }
+}
+
+impl<I: Clone> DynId<I> {
+    pub fn value(&self) -> I {
+        self.id.clone()
+    }
+}
+
+impl<T: 'static, I> From<Id<T, I>> for DynId<I> {
+    fn from(id: Id<T, I>) -> Self {
+        Self {
+            id: id.id,
+            owner: TypeId::of::<T>(),
+            owner_name: type_name::<T>(),
+        }
+    }
+}
+
+impl<I: Debug> Debug for DynId<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DynId<owner: {}, backing: {}>({:?})",
+            self.owner_name,
+            type_name::<I>(),
+            self.id
+        )
+    }
+}
+
+impl<I: Clone> Clone for DynId<I> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            owner: self.owner,
+            owner_name: self.owner_name,
+        }
+    }
+}
+
+impl<I: Copy> Copy for DynId<I> {}
+
+impl<I: PartialEq> PartialEq for DynId<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.owner == other.owner && self.id == other.id
+    }
+}
+
+impl<I: Eq> Eq for DynId<I> {}
+
+impl<I: Hash> Hash for DynId<I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.owner.hash(state);
+        self.id.hash(state);
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    env::{self, VarError},
+    fmt::Display,
+    str::FromStr,
+};
+
+use crate::{FromEnvError, Id};
+
+impl<T, I> Id<T, I>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    /// Reads & parses an ID from the environment variable `var`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{FromEnvError, Id};
+    /// let err = Id::<String>::from_env("TYPED_ID_DOC_UNSET").unwrap_err();
+    /// assert_eq!(err, FromEnvError::Missing("TYPED_ID_DOC_UNSET".to_string()));
+    /// ```
+    pub fn from_env(var: &str) -> Result<Self, FromEnvError> {
+        Self::from_env_with(var, |var| env::var(var))
+    }
+
+    /// Like [`Id::from_env`], reading the variable through `lookup`
+    ///
+    /// Lets tests supply variables without `std::env::set_var`, which is unsound while other
+    /// threads may read the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env::VarError;
+    /// use typed_id::{FromEnvError, Id};
+    /// let lookup = |_: &str| Ok::<_, VarError>("abc".to_string());
+    ///
+    /// let err = Id::<String>::from_env_with("USER_ID", lookup).unwrap_err();
+    /// assert!(matches!(err, FromEnvError::Invalid { .. }));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"environment variable USER_ID: invalid alloc::string::String id "abc": invalid digit found in string"#
+    /// );
+    /// ```
+    pub fn from_env_with(
+        var: &str,
+        lookup: impl FnOnce(&str) -> Result<String, VarError>,
+    ) -> Result<Self, FromEnvError> {
+        let value = lookup(var).map_err(|e| match e {
+            VarError::NotPresent => FromEnvError::Missing(var.to_string()),
+            VarError::NotUnicode(_) => FromEnvError::NotUnicode(var.to_string()),
+        })?;
+
+        value.parse().map_err(|error| FromEnvError::Invalid {
+            var: var.to_string(),
+            error,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    any::{Any, TypeId, type_name},
+    cmp::Ordering,
+};
+
+use crate::Id;
+
+/// Object-safe view of an [`Id`] with any owner & backing type
+///
+/// `dyn ErasedId` has a total order, so IDs of different owners can share a `BTreeMap` or be
+/// sorted together. IDs are ordered by owner type name, then backing type name, then value.
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use typed_id::{ErasedId, Id};
+///
+/// let mut ids = BTreeSet::<Box<dyn ErasedId>>::new();
+/// ids.insert(Box::new(Id::<String>::new(2)));
+/// ids.insert(Box::new(Id::<bool>::new(9)));
+/// ids.insert(Box::new(Id::<String>::new(1)));
+///
+/// let owners: Vec<_> = ids.iter().map(|id| id.owner_type()).collect();
+/// assert_eq!(owners, ["alloc::string::String", "alloc::string::String", "bool"]);
+/// ```
+pub trait ErasedId: Any {
+    fn owner_type(&self) -> &'static str;
+    fn backing_type(&self) -> &'static str;
+    fn owner_type_id(&self) -> TypeId;
+    fn backing_type_id(&self) -> TypeId;
+    fn as_any(&self) -> &dyn Any;
+
+    /// Compares backing values, assuming `other` has the same owner & backing types
+    fn cmp_value(&self, other: &dyn ErasedId) -> Ordering;
+}
+
+impl<T: 'static, I: Ord + 'static> ErasedId for Id<T, I> {
+    fn owner_type(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    fn backing_type(&self) -> &'static str {
+        type_name::<I>()
+    }
+
+    fn owner_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn backing_type_id(&self) -> TypeId {
+        TypeId::of::<I>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn cmp_value(&self, other: &dyn ErasedId) -> Ordering {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(Ordering::Equal, |other| self.id.cmp(&other.id))
+    }
+}
+
+impl PartialEq for dyn ErasedId {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for dyn ErasedId {}
+
+impl PartialOrd for dyn ErasedId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn ErasedId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // type names can collide, so the unordered-but-unique TypeIds break ties
+        self.owner_type()
+            .cmp(other.owner_type())
+            .then_with(|| self.owner_type_id().cmp(&other.owner_type_id()))
+            .then_with(|| self.backing_type().cmp(other.backing_type()))
+            .then_with(|| self.backing_type_id().cmp(&other.backing_type_id()))
+            .then_with(|| self.cmp_value(other))
+    }
+}
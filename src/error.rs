@@ -0,0 +1,187 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// Error returned when a string cannot be parsed into an [`Id`](crate::Id)
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::Id;
+/// let err = "abc".parse::<Id<String>>().unwrap_err();
+/// assert_eq!(err.input(), "abc");
+/// assert_eq!(err.owner_type(), "alloc::string::String");
+/// assert_eq!(
+///     err.to_string(),
+///     r#"invalid alloc::string::String id "abc": invalid digit found in string"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError {
+    input: String,
+    owner: &'static str,
+    kind: ParseIdErrorKind,
+    index: Option<usize>,
+}
+
+/// Reason a [`ParseIdError`] occurred
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIdErrorKind {
+    /// The backing type failed to parse, with its error message
+    Backing(String),
+    /// The separator between an ID's parts was missing
+    MissingSeparator(char),
+    /// The input was empty
+    Empty,
+    /// The input contained a character that isn't a valid digit
+    InvalidDigit(char),
+    /// The value doesn't fit in the backing type
+    Overflow,
+    /// The input isn't a validly encoded ID
+    Malformed,
+    /// The input's check digit doesn't match its value
+    ChecksumMismatch,
+    /// The value is above the maximum the caller accepts
+    AboveMax(String),
+    /// The value is zero where IDs must be nonzero
+    Zero,
+    /// The ID's prefix belongs to a different owner
+    PrefixMismatch {
+        expected: &'static str,
+        found: String,
+    },
+}
+
+impl ParseIdError {
+    pub(crate) fn new(input: &str, owner: &'static str, kind: ParseIdErrorKind) -> Self {
+        Self {
+            input: input.to_string(),
+            owner,
+            kind,
+            index: None,
+        }
+    }
+
+    pub(crate) fn at_index(self, index: usize) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// The string that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Name of the owner type of the ID being parsed
+    pub fn owner_type(&self) -> &str {
+        self.owner
+    }
+
+    pub fn kind(&self) -> &ParseIdErrorKind {
+        &self.kind
+    }
+
+    /// Which element of a list failed, see [`Id::parse_list`](crate::Id::parse_list)
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl Display for ParseIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} id {:?}", self.owner, self.input)?;
+        if let Some(index) = self.index {
+            write!(f, " at index {index}")?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl Display for ParseIdErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Backing(msg) => f.write_str(msg),
+            Self::MissingSeparator(sep) => write!(f, "missing separator {sep:?}"),
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidDigit(c) => write!(f, "invalid digit {c:?}"),
+            Self::Overflow => f.write_str("value too large for backing type"),
+            Self::Malformed => f.write_str("malformed encoding"),
+            Self::ChecksumMismatch => f.write_str("checksum mismatch"),
+            Self::AboveMax(max) => write!(f, "value above maximum {max}"),
+            Self::Zero => f.write_str("id must be nonzero"),
+            Self::PrefixMismatch { expected, found } => {
+                write!(f, "expected prefix {expected:?}, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseIdError {}
+
+/// Error returned by [`Id::from_env`](crate::Id::from_env)
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{FromEnvError, Id};
+/// let err = Id::<String>::from_env("TYPED_ID_DOC_UNSET").unwrap_err();
+/// assert_eq!(err, FromEnvError::Missing("TYPED_ID_DOC_UNSET".to_string()));
+/// assert_eq!(err.to_string(), "environment variable TYPED_ID_DOC_UNSET is not set");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FromEnvError {
+    /// The variable isn't set
+    Missing(String),
+    /// The variable's value isn't valid unicode
+    NotUnicode(String),
+    /// The variable's value isn't a valid ID
+    Invalid { var: String, error: ParseIdError },
+}
+
+impl Display for FromEnvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(var) => write!(f, "environment variable {var} is not set"),
+            Self::NotUnicode(var) => write!(f, "environment variable {var} is not valid unicode"),
+            Self::Invalid { var, error } => write!(f, "environment variable {var}: {error}"),
+        }
+    }
+}
+
+impl Error for FromEnvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Failure to decode a LEB128 varint ID, see [`Id::from_varint`](crate::Id::from_varint)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VarintError {
+    /// The input ended before a byte without the continuation bit
+    Truncated,
+    /// The value doesn't fit in the backing
+    Overflow,
+    /// The value has a shorter encoding, so the bytes aren't what [`Id::to_varint`](crate::Id::to_varint) writes
+    Overlong,
+}
+
+impl Display for VarintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "varint is truncated"),
+            Self::Overflow => write!(f, "varint is too large for the backing"),
+            Self::Overlong => write!(f, "varint is not minimally encoded"),
+        }
+    }
+}
+
+impl Error for VarintError {}
@@ -0,0 +1,78 @@
+use std::{
+    fmt::{Debug, Display, Formatter},
+    ops::Deref,
+};
+
+use crate::Id;
+
+/// String backing that is always exactly N characters long
+///
+/// For code-style IDs like invite codes, where any other length is malformed.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::FixedStr;
+/// assert_eq!(FixedStr::<6>::new("XK4P2Q").as_deref(), Some("XK4P2Q"));
+/// assert_eq!(FixedStr::<6>::new("XK4P2"), None);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedStr<const N: usize>(String);
+
+impl<const N: usize> FixedStr<N> {
+    /// Returns `None` unless `s` is exactly N characters long
+    pub fn new(s: impl Into<String>) -> Option<Self> {
+        let s = s.into();
+        (s.chars().count() == N).then_some(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for FixedStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedStr<N> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> Display for FixedStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> Debug for FixedStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T, const N: usize> Id<T, FixedStr<N>> {
+    /// Creates an ID only if `code` is exactly N characters long
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{FixedStr, Id};
+    /// let invite = Id::<String, FixedStr<6>>::new_fixed("XK4P2Q").unwrap();
+    /// assert_eq!(invite.to_string(), "XK4P2Q");
+    /// assert!(Id::<String, FixedStr<6>>::new_fixed("XK4P2Q7").is_none());
+    /// ```
+    pub fn new_fixed(code: impl Into<String>) -> Option<Self> {
+        FixedStr::new(code).map(Id::new)
+    }
+}
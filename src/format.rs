@@ -0,0 +1,207 @@
+use std::{
+    any::type_name,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use crate::{DefaultIdType, Id, IdPrefix, ParseIdError, ParseIdErrorKind, UnsignedBacking};
+
+/// A textual format for IDs, used by [`FormattedId`]'s `Display` & `FromStr`
+///
+/// Implement it to plug in a custom format; [`Decimal`], [`Hex`], [`Base62`] & [`Prefixed`]
+/// are built in.
+pub trait IdFormat<T, I> {
+    fn format(id: &Id<T, I>, f: &mut Formatter<'_>) -> std::fmt::Result;
+    fn parse(s: &str) -> Result<Id<T, I>, ParseIdError>;
+}
+
+/// The backing's own `Display` & `FromStr`, same as a plain [`Id`]
+///
+/// Parsing only accepts what `format` writes, so `+7` & `007` are rejected for integers.
+pub struct Decimal;
+
+/// Lowercase hexadecimal without a `0x` prefix
+///
+/// Parsing accepts only what `format` writes: uppercase digits & leading zeros are rejected.
+pub struct Hex;
+
+/// Base62, see [`Id::to_base62`]
+pub struct Base62;
+
+/// `{prefix}_{base62}` using the owner's [`IdPrefix`], see [`Id::public_id`]
+pub struct Prefixed;
+
+impl<T, I> IdFormat<T, I> for Decimal
+where
+    I: Display + FromStr,
+    I::Err: Display,
+{
+    fn format(id: &Id<T, I>, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(id, f)
+    }
+
+    fn parse(s: &str) -> Result<Id<T, I>, ParseIdError> {
+        let id: Id<T, I> = s.parse()?;
+        if id.to_string() != s {
+            return Err(ParseIdError::new(
+                s,
+                type_name::<T>(),
+                ParseIdErrorKind::Malformed,
+            ));
+        }
+        Ok(id)
+    }
+}
+
+impl<T, I: UnsignedBacking> IdFormat<T, I> for Hex {
+    fn format(id: &Id<T, I>, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}", id.id.to_u128())
+    }
+
+    fn parse(s: &str) -> Result<Id<T, I>, ParseIdError> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        if s.is_empty() {
+            return Err(error(ParseIdErrorKind::Empty));
+        }
+        if s.len() > 1 && s.starts_with('0') {
+            return Err(error(ParseIdErrorKind::Malformed));
+        }
+
+        let mut value: u128 = 0;
+        for c in s.chars() {
+            let digit = Some(c)
+                .filter(|c| !c.is_ascii_uppercase())
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| error(ParseIdErrorKind::InvalidDigit(c)))?;
+            value = value
+                .checked_mul(16)
+                .and_then(|v| v.checked_add(digit.into()))
+                .ok_or_else(|| error(ParseIdErrorKind::Overflow))?;
+        }
+
+        I::from_u128(value)
+            .map(Id::new)
+            .ok_or_else(|| error(ParseIdErrorKind::Overflow))
+    }
+}
+
+impl<T, I: UnsignedBacking> IdFormat<T, I> for Base62 {
+    fn format(id: &Id<T, I>, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&id.to_base62())
+    }
+
+    fn parse(s: &str) -> Result<Id<T, I>, ParseIdError> {
+        Id::from_base62(s)
+    }
+}
+
+impl<T: IdPrefix, I: UnsignedBacking> IdFormat<T, I> for Prefixed {
+    fn format(id: &Id<T, I>, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&id.public_id())
+    }
+
+    fn parse(s: &str) -> Result<Id<T, I>, ParseIdError> {
+        Id::from_public_id(s)
+    }
+}
+
+/// [`Id`] whose `Display` & `FromStr` use the format F
+///
+/// Pick the format once in a type alias so every place an owner's IDs are printed or parsed
+/// agrees on it.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{FormattedId, Hex, Id};
+///
+/// struct User;
+/// type UserId = FormattedId<User, Hex>;
+///
+/// let id = UserId::from(Id::new(255));
+/// assert_eq!(id.to_string(), "ff");
+/// assert_eq!("ff".parse::<UserId>().unwrap(), id);
+/// assert!("FF".parse::<UserId>().is_err());
+/// ```
+pub struct FormattedId<T, F, I = DefaultIdType> {
+    id: Id<T, I>,
+    f: PhantomData<fn() -> F>,
+}
+
+impl<T, F, I> FormattedId<T, F, I> {
+    pub fn new(id: Id<T, I>) -> Self {
+        Self { id, f: PhantomData }
+    }
+
+    pub fn id(&self) -> &Id<T, I> {
+        &self.id
+    }
+
+    pub fn into_id(self) -> Id<T, I> {
+        self.id
+    }
+}
+
+impl<T, I> Id<T, I> {
+    /// Wraps the ID so it displays & parses in the format F
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Base62, Id};
+    /// assert_eq!(Id::<String>::new(1234).formatted::<Base62>().to_string(), "Ju");
+    /// ```
+    pub fn formatted<F: IdFormat<T, I>>(self) -> FormattedId<T, F, I> {
+        FormattedId::new(self)
+    }
+}
+
+impl<T, F, I> From<Id<T, I>> for FormattedId<T, F, I> {
+    fn from(id: Id<T, I>) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<T, F: IdFormat<T, I>, I> Display for FormattedId<T, F, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        F::format(&self.id, f)
+    }
+}
+
+impl<T, F: IdFormat<T, I>, I> FromStr for FormattedId<T, F, I> {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        F::parse(s).map(Self::new)
+    }
+}
+
+impl<T, F, I: Debug> Debug for FormattedId<T, F, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.id, f)
+    }
+}
+
+impl<T, F, I: Clone> Clone for FormattedId<T, F, I> {
+    fn clone(&self) -> Self {
+        Self::new(self.id.clone())
+    }
+}
+
+impl<T, F, I: Copy> Copy for FormattedId<T, F, I> {}
+
+impl<T, F, I: PartialEq> PartialEq for FormattedId<T, F, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T, F, I: Eq> Eq for FormattedId<T, F, I> {}
+
+impl<T, F, I: Hash> Hash for FormattedId<T, F, I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
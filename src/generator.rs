@@ -0,0 +1,195 @@
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
+
+use crate::{DefaultIdType, Id, IntegerBacking};
+
+/// Generates sequential IDs for an owner type T
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, IdGenerator};
+/// let mut ids = IdGenerator::<String>::new(1);
+/// assert_eq!(ids.next_id(), Some(Id::new(1)));
+/// assert_eq!(ids.next_id(), Some(Id::new(2)));
+/// ```
+pub struct IdGenerator<T, I = DefaultIdType> {
+    next: Option<I>,
+    stride: I,
+    t: PhantomData<fn() -> T>,
+}
+
+impl<T, I: IntegerBacking> IdGenerator<T, I> {
+    pub fn new(start: I) -> Self {
+        Self::with_stride(start, I::ONE)
+    }
+
+    /// Generates `start`, `start + stride`, `start + 2 * stride`, ...
+    ///
+    /// Generators with the same stride & different starts below the stride never overlap, so
+    /// shard N of M can use `with_stride(N, M)`.
+    ///
+    /// # Panics
+    ///
+    /// If `stride` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, IdGenerator};
+    /// let shard_1: Vec<_> = IdGenerator::<String>::with_stride(1, 3).take(3).collect();
+    /// assert_eq!(shard_1, [Id::new(1), Id::new(4), Id::new(7)]);
+    /// ```
+    pub fn with_stride(start: I, stride: I) -> Self {
+        assert!(stride > I::ZERO, "IdGenerator stride must be positive");
+        Self {
+            next: Some(start),
+            stride,
+            t: PhantomData,
+        }
+    }
+
+    /// Returns the next ID, or `None` once the backing type is exhausted
+    pub fn next_id(&mut self) -> Option<Id<T, I>> {
+        let current = self.next?;
+        self.next = current.checked_add(self.stride);
+        Some(Id::new(current))
+    }
+
+    /// Returns the next ID not in `blocked`, or `None` once the backing type is exhausted
+    ///
+    /// Blocked values are skipped for good, so IDs still increase monotonically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use typed_id::{Id, IdGenerator};
+    /// let mut ids = IdGenerator::<String>::new(12);
+    /// let blocked = HashSet::from([13]);
+    /// assert_eq!(ids.next_skipping(&blocked), Some(Id::new(12)));
+    /// assert_eq!(ids.next_skipping(&blocked), Some(Id::new(14)));
+    /// ```
+    pub fn next_skipping<S: BuildHasher>(&mut self, blocked: &HashSet<I, S>) -> Option<Id<T, I>> {
+        self.find(|id| !blocked.contains(&id.id))
+    }
+}
+
+impl<T, I: IntegerBacking> Iterator for IdGenerator<T, I> {
+    type Item = Id<T, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_id()
+    }
+}
+
+impl<T, I: Debug> Debug for IdGenerator<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IdGenerator")
+            .field("next", &self.next)
+            .field("stride", &self.stride)
+            .finish()
+    }
+}
+
+impl<T, I: Clone> Clone for IdGenerator<T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next.clone(),
+            stride: self.stride.clone(),
+            t: PhantomData,
+        }
+    }
+}
+
+/// Thread-safe sequential `u64` ID generator for an owner type T
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{AtomicIdGenerator, Id};
+/// let ids = AtomicIdGenerator::<String>::new(1);
+/// assert_eq!(ids.next_id(), Some(Id::new(1)));
+/// assert_eq!(ids.next_id(), Some(Id::new(2)));
+/// ```
+pub struct AtomicIdGenerator<T> {
+    next: AtomicU64,
+    exhausted: AtomicBool,
+    t: PhantomData<fn() -> T>,
+}
+
+impl<T> AtomicIdGenerator<T> {
+    pub const fn new(start: u64) -> Self {
+        Self {
+            next: AtomicU64::new(start),
+            exhausted: AtomicBool::new(false),
+            t: PhantomData,
+        }
+    }
+
+    /// Returns the next ID, or `None` once `u64::MAX` has been handed out
+    pub fn next_id(&self) -> Option<Id<T, u64>> {
+        match self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_add(1))
+        {
+            Ok(current) => Some(Id::new(current)),
+            // u64::MAX itself can only be handed out once
+            Err(max) => (!self.exhausted.swap(true, Ordering::Relaxed)).then(|| Id::new(max)),
+        }
+    }
+
+    /// Generates `n` IDs on `threads` threads, all distinct from each other & from any other
+    /// IDs from this generator
+    ///
+    /// The IDs are grouped by thread, so they aren't in order. Fewer than `n` are returned if
+    /// the generator runs out.
+    ///
+    /// # Panics
+    ///
+    /// If `threads` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::AtomicIdGenerator;
+    /// let generator = AtomicIdGenerator::<String>::new(0);
+    /// let mut ids = generator.generate_parallel(1000, 4);
+    /// ids.sort();
+    /// assert_eq!(ids.len(), 1000);
+    /// assert_eq!(ids[999].value(), 999);
+    /// ```
+    pub fn generate_parallel(&self, n: usize, threads: usize) -> Vec<Id<T, u64>> {
+        assert!(threads > 0, "generate_parallel needs at least one thread");
+        let per_thread = n / threads;
+        let remainder = n % threads;
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|i| {
+                    let count = per_thread + usize::from(i < remainder);
+                    scope.spawn(move || (0..count).map_while(|_| self.next_id()).collect())
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| -> Vec<_> { handle.join().expect("generator thread panicked") })
+                .collect()
+        })
+    }
+}
+
+impl<T> Debug for AtomicIdGenerator<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AtomicIdGenerator")
+            .field("next", &self.next)
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    rc::Rc,
+    sync::Arc,
+    thread::{self, ThreadId},
+};
+
+use crate::{FixedStr, Id};
+
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// FNV-1a hasher with a fixed seed, so hashes are the same across runs
+///
+/// std's `DefaultHasher` makes no promises about its algorithm, & `RandomState` is randomly
+/// seeded. The output is mixed with a finalizer since FNV alone distributes small inputs
+/// poorly.
+///
+/// Only [`StableHasher::hash_bytes`] is stable across platforms & toolchains: feeding it a
+/// value's std `Hash` impl brings in native endianness, `usize` length prefixes & other
+/// unspecified std details.
+pub(crate) struct StableHasher(u64);
+
+impl StableHasher {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn hash_one(value: impl Hash) -> u64 {
+        let mut hasher = Self::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(crate) fn hash_bytes(bytes: &[u8]) -> u64 {
+        let mut hasher = Self::new();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        // finalizer from MurmurHash3
+        let mut h = self.0;
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        h ^ (h >> 33)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Backings with a canonical byte encoding, so hashes of them are the same on every platform
+/// & toolchain
+///
+/// Integers encode as little-endian, with `usize` & `isize` widened to 64 bits; strings as
+/// their UTF-8 bytes. See [`Id::shard`].
+pub trait StableBytes {
+    fn stable_bytes(&self) -> Cow<'_, [u8]>;
+}
+
+macro_rules! int_stable_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl StableBytes for $t {
+                fn stable_bytes(&self) -> Cow<'_, [u8]> {
+                    Cow::Owned(self.to_le_bytes().to_vec())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! str_stable_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl StableBytes for $t {
+                fn stable_bytes(&self) -> Cow<'_, [u8]> {
+                    Cow::Borrowed(self.as_bytes())
+                }
+            }
+        )*
+    };
+}
+
+int_stable_bytes! { u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
+str_stable_bytes! { str, String, Box<str>, Rc<str>, Arc<str>, Cow<'_, str> }
+
+impl StableBytes for usize {
+    fn stable_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned((*self as u64).to_le_bytes().to_vec())
+    }
+}
+
+impl StableBytes for isize {
+    fn stable_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned((*self as i64).to_le_bytes().to_vec())
+    }
+}
+
+impl<S: StableBytes + ?Sized> StableBytes for &S {
+    fn stable_bytes(&self) -> Cow<'_, [u8]> {
+        (**self).stable_bytes()
+    }
+}
+
+impl<const N: usize> StableBytes for FixedStr<N> {
+    fn stable_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_str().as_bytes())
+    }
+}
+
+impl<const N: usize> StableBytes for [u8; N] {
+    fn stable_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<T, I: StableBytes> Id<T, I> {
+    /// Routes the ID to one of `shard_count` shards by a fixed-seed hash of its backing
+    ///
+    /// The backing's [`StableBytes`] are hashed, so routing is the same across runs,
+    /// processes, platforms & toolchains. Changing `shard_count` moves most IDs to different
+    /// shards.
+    ///
+    /// # Panics
+    ///
+    /// If `shard_count` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(1234);
+    /// assert!(id.shard(16) < 16);
+    /// assert_eq!(id.shard(16), id.shard(16));
+    /// ```
+    pub fn shard(&self, shard_count: u64) -> u64 {
+        assert!(shard_count > 0, "shard_count must be positive");
+        StableHasher::hash_bytes(&self.id.stable_bytes()) % shard_count
+    }
+
+    /// Whether the ID falls in the first `percent`% of IDs, for feature flag rollouts
+    ///
+    /// Hashes the backing's [`StableBytes`], so an ID's bucket is the same in every service &
+    /// release, & raising `percent` only ever adds IDs to the rollout. Values above 100 include
+    /// every ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(1234);
+    /// assert!(!id.in_rollout(0));
+    /// assert!(id.in_rollout(100));
+    /// assert_eq!(id.in_rollout(25), id.in_rollout(25));
+    /// ```
+    pub fn in_rollout(&self, percent: u8) -> bool {
+        // salted so rollouts don't line up with shards
+        let mut bytes = self.id.stable_bytes().into_owned();
+        bytes.extend_from_slice(b"rollout");
+        StableHasher::hash_bytes(&bytes) % 100 < u64::from(percent)
+    }
+
+    /// Short fingerprint of the ID for display, in Crockford base32
+    ///
+    /// Hashes the backing's [`StableBytes`], so codes are the same across runs, platforms &
+    /// toolchains. Not reversible & not unique: different IDs can share a code, especially for
+    /// small `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(1234);
+    /// assert_eq!(id.short_code(6).len(), 6);
+    /// assert_eq!(id.short_code(6), id.short_code(6));
+    /// assert!(id.short_code(8).starts_with(&id.short_code(6)));
+    /// ```
+    pub fn short_code(&self, len: usize) -> String {
+        // each 64 bit hash yields 12 characters of 5 bits
+        (0..len.div_ceil(12))
+            .flat_map(|round| {
+                let mut bytes = self.id.stable_bytes().into_owned();
+                bytes.extend_from_slice(&(round as u64).to_le_bytes());
+                let hash = StableHasher::hash_bytes(&bytes);
+                (0..12).map(move |i| CROCKFORD_BASE32[(hash >> (i * 5)) as usize & 0x1f] as char)
+            })
+            .take(len)
+            .collect()
+    }
+}
+
+impl<T> Id<T, u64> {
+    /// Maps a `ThreadId` to an ID, for keying per-thread state by typed IDs
+    ///
+    /// The same thread always maps to the same ID within a process. `ThreadId` is opaque, so
+    /// this hashes it: IDs aren't meaningful across processes, & two threads could in principle
+    /// collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use typed_id::Id;
+    /// let here = Id::<String, u64>::from_thread_id(thread::current().id());
+    /// assert_eq!(here, Id::current_thread());
+    /// ```
+    pub fn from_thread_id(id: ThreadId) -> Self {
+        Id::new(StableHasher::hash_one(id))
+    }
+
+    /// Derives a deterministic ID from a business key like an email, by a fixed-seed hash
+    ///
+    /// The key's raw bytes are hashed, so the same key yields the same ID across runs,
+    /// platforms & toolchains, & upserts don't need a central sequence. Distinct keys can
+    /// collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// struct User;
+    /// let id = Id::<User, u64>::from_natural_key("ada@example.com");
+    /// assert_eq!(id, Id::from_natural_key("ada@example.com"));
+    /// assert_ne!(id, Id::from_natural_key("grace@example.com"));
+    /// ```
+    pub fn from_natural_key(key: impl AsRef<[u8]>) -> Self {
+        Id::new(StableHasher::hash_bytes(key.as_ref()))
+    }
+
+    /// The ID of the calling thread, see [`Id::from_thread_id`]
+    pub fn current_thread() -> Self {
+        Self::from_thread_id(thread::current().id())
+    }
+}
@@ -0,0 +1,150 @@
+use std::any::type_name;
+
+use crate::{Id, ParseIdError, ParseIdErrorKind, UnsignedBacking};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+const SEPARATORS: &str = "cfhistuCFHISTU";
+const GUARD_DIV: usize = 12;
+
+/// Alphabets of the Hashids algorithm, derived from a salt
+struct Alphabets {
+    alphabet: Vec<char>,
+    guards: Vec<char>,
+    salt: Vec<char>,
+}
+
+impl Alphabets {
+    fn new(salt: &str) -> Self {
+        let salt: Vec<char> = salt.chars().collect();
+        let mut alphabet: Vec<char> = ALPHABET
+            .chars()
+            .filter(|c| !SEPARATORS.contains(*c))
+            .collect();
+        shuffle(&mut alphabet, &salt);
+
+        // the default alphabet has enough separators that none move between alphabets, & the
+        // separators themselves only matter when encoding multiple numbers
+        let guard_count = alphabet.len().div_ceil(GUARD_DIV);
+        let guards = alphabet.drain(..guard_count).collect();
+
+        Self {
+            alphabet,
+            guards,
+            salt,
+        }
+    }
+
+    /// Alphabet used to encode a number, after the lottery character
+    fn for_lottery(&self, lottery: char) -> Vec<char> {
+        let mut alphabet = self.alphabet.clone();
+        let buffer: Vec<char> = std::iter::once(lottery)
+            .chain(self.salt.iter().copied())
+            .chain(alphabet.iter().copied())
+            .take(alphabet.len())
+            .collect();
+        shuffle(&mut alphabet, &buffer);
+        alphabet
+    }
+
+    fn encode(&self, value: u128) -> String {
+        let lottery = self.alphabet[(value % 100) as usize % self.alphabet.len()];
+        let alphabet = self.for_lottery(lottery);
+        let base = alphabet.len() as u128;
+
+        let mut digits = Vec::new();
+        let mut rest = value;
+        loop {
+            digits.push(alphabet[(rest % base) as usize]);
+            rest /= base;
+            if rest == 0 {
+                break;
+            }
+        }
+
+        std::iter::once(lottery)
+            .chain(digits.into_iter().rev())
+            .collect()
+    }
+
+    fn decode(&self, hashid: &str) -> Result<u128, ParseIdErrorKind> {
+        let parts: Vec<&str> = hashid.split(|c| self.guards.contains(&c)).collect();
+        let part = match parts.len() {
+            2 | 3 => parts[1],
+            _ => parts[0],
+        };
+
+        let mut chars = part.chars();
+        let lottery = chars.next().ok_or(ParseIdErrorKind::Empty)?;
+        let alphabet = self.for_lottery(lottery);
+        let base = alphabet.len() as u128;
+
+        let mut value: u128 = 0;
+        for c in chars {
+            let digit = alphabet
+                .iter()
+                .position(|a| *a == c)
+                .ok_or(ParseIdErrorKind::InvalidDigit(c))?;
+            value = value
+                .checked_mul(base)
+                .and_then(|v| v.checked_add(digit as u128))
+                .ok_or(ParseIdErrorKind::Overflow)?;
+        }
+
+        if self.encode(value) == hashid {
+            Ok(value)
+        } else {
+            Err(ParseIdErrorKind::Malformed)
+        }
+    }
+}
+
+/// Hashids' consistent shuffle, permuting `alphabet` based on `salt`
+fn shuffle(alphabet: &mut [char], salt: &[char]) {
+    if salt.is_empty() {
+        return;
+    }
+
+    let mut p = 0;
+    for (v, i) in (1..alphabet.len()).rev().enumerate() {
+        let v = v % salt.len();
+        let n = salt[v] as usize;
+        p += n;
+        alphabet.swap(i, (n + v + p) % i);
+    }
+}
+
+impl<T, I: UnsignedBacking> Id<T, I> {
+    /// Encodes the ID as an obfuscated [Hashids](https://hashids.org) string
+    ///
+    /// This hides sequential IDs from casual enumeration but is reversible by anyone who knows
+    /// the salt, so it is not encryption.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(12345);
+    /// assert_eq!(id.to_hashid("this is my salt"), "NkK9");
+    /// ```
+    pub fn to_hashid(&self, salt: &str) -> String {
+        Alphabets::new(salt).encode(self.id.to_u128())
+    }
+
+    /// Decodes a string produced by [`Id::to_hashid`] with the same salt
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::from_hashid("NkK9", "this is my salt"), Ok(Id::new(12345)));
+    /// assert!(Id::<String>::from_hashid("NkK9", "another salt").is_err());
+    /// ```
+    pub fn from_hashid(hashid: &str, salt: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(hashid, type_name::<T>(), kind);
+        let value = Alphabets::new(salt).decode(hashid).map_err(error)?;
+
+        I::from_u128(value)
+            .map(Id::new)
+            .ok_or_else(|| error(ParseIdErrorKind::Overflow))
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    any::type_name,
+    fmt::{LowerHex, UpperHex, Write},
+};
+
+use crate::{Id, ParseIdError, ParseIdErrorKind};
+
+/// Formats the bytes as lowercase hex, most significant first
+///
+/// Byte arrays have no `Display`, so hex is how content-addressed IDs like hashes print.
+/// Width, fill, alignment & `#` behave as for integers, with `#` adding a `0x` prefix.
+///
+/// # Examples
+///
+/// ```
+/// use typed_id::Id;
+/// let id = Id::<String, [u8; 2]>::new([0xbe, 0xef]);
+/// assert_eq!(format!("{id:x}"), "beef");
+/// assert_eq!(format!("{id:X}"), "BEEF");
+/// assert_eq!(format!("{id:>6x}"), "  beef");
+/// assert_eq!(format!("{id:#08x}"), "0x00beef");
+/// ```
+impl<T, const N: usize> LowerHex for Id<T, [u8; N]> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut hex = String::with_capacity(N * 2);
+        for byte in &self.id {
+            write!(hex, "{byte:02x}")?;
+        }
+        f.pad_integral(true, "0x", &hex)
+    }
+}
+
+impl<T, const N: usize> UpperHex for Id<T, [u8; N]> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut hex = String::with_capacity(N * 2);
+        for byte in &self.id {
+            write!(hex, "{byte:02X}")?;
+        }
+        f.pad_integral(true, "0x", &hex)
+    }
+}
+
+impl<T, const N: usize> Id<T, [u8; N]> {
+    /// Encodes the bytes as lowercase hex, two characters per byte
+    pub fn to_hex(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Decodes exactly 2 * N lowercase hex characters, as written by [`Id::to_hex`]
+    ///
+    /// Uppercase digits are rejected, so each ID has exactly one hex form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, ParseIdErrorKind};
+    /// assert_eq!(Id::<String, [u8; 2]>::from_hex("beef"), Ok(Id::new([0xbe, 0xef])));
+    ///
+    /// let err = Id::<String, [u8; 2]>::from_hex("BEef").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('B'));
+    ///
+    /// let err = Id::<String, [u8; 2]>::from_hex("bee").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseIdErrorKind::Malformed);
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        if let Some(c) = s
+            .chars()
+            .find(|c| !c.is_ascii_digit() && !('a'..='f').contains(c))
+        {
+            return Err(error(ParseIdErrorKind::InvalidDigit(c)));
+        }
+        if s.len() != N * 2 {
+            return Err(error(ParseIdErrorKind::Malformed));
+        }
+
+        let mut bytes = [0; N];
+        for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+            let pair = std::str::from_utf8(pair).expect("hex digits are ASCII");
+            *byte = u8::from_str_radix(pair, 16).expect("validated hex digits");
+        }
+        Ok(Id::new(bytes))
+    }
+}
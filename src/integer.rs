@@ -0,0 +1,388 @@
+use std::{
+    any::type_name,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::BitXor,
+    str::FromStr,
+};
+
+use crate::{Id, ParseIdError, ParseIdErrorKind};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integer backing types
+///
+/// Sealed & implemented for every primitive integer. Methods requiring integer arithmetic on
+/// the backing are available on IDs backed by these types.
+pub trait IntegerBacking:
+    sealed::Sealed + Copy + Ord + Hash + Debug + Display + FromStr + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const BITS: u32;
+    const SIGNED: bool;
+
+    /// Byte array as wide as the type
+    type Bytes: Copy;
+
+    /// Converts from a `u128`, returning `None` if the value doesn't fit
+    fn from_u128(value: u128) -> Option<Self>;
+
+    /// Converts to an `i128`, returning `None` if the value doesn't fit
+    fn to_i128(self) -> Option<i128>;
+
+    /// Converts from an `i128`, returning `None` if the value doesn't fit
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Returns `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Big-endian bytes, with the sign bit flipped for signed types
+    fn to_sortable_bytes(self) -> Vec<u8>;
+
+    fn to_ne_bytes(self) -> Self::Bytes;
+
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// Primitive unsigned integer backing types
+pub trait UnsignedBacking: IntegerBacking {
+    /// Losslessly widens to a `u128`
+    fn to_u128(self) -> u128;
+}
+
+/// Numeric value of an ID for tagging metrics, regardless of backing width
+///
+/// ```
+/// use typed_id::{AsMetricValue, Id};
+/// assert_eq!(Id::<String, u8>::new(7).as_metric_value(), 7);
+/// assert_eq!(Id::<String, u64>::new(u64::MAX).as_metric_value(), i64::MAX);
+/// ```
+pub trait AsMetricValue {
+    /// The value as an `i64`, saturating if it doesn't fit
+    fn as_metric_value(&self) -> i64;
+}
+
+macro_rules! integer_backing {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl IntegerBacking for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                const BITS: u32 = <$t>::BITS;
+                const SIGNED: bool = <$t>::MIN != 0;
+
+                type Bytes = [u8; size_of::<$t>()];
+
+                fn from_u128(value: u128) -> Option<Self> {
+                    value.try_into().ok()
+                }
+
+                fn to_i128(self) -> Option<i128> {
+                    self.try_into().ok()
+                }
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    value.try_into().ok()
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn to_sortable_bytes(self) -> Vec<u8> {
+                    let mut bytes = self.to_be_bytes();
+                    // flipping the sign bit moves negatives below positives
+                    if Self::SIGNED {
+                        bytes[0] ^= 0x80;
+                    }
+                    bytes.to_vec()
+                }
+
+                fn to_ne_bytes(self) -> Self::Bytes {
+                    <$t>::to_ne_bytes(self)
+                }
+
+                fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_ne_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned_backing {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl UnsignedBacking for $t {
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+integer_backing! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+unsigned_backing! { u8, u16, u32, u64, u128, usize }
+
+impl<T, I: IntegerBacking> AsMetricValue for Id<T, I> {
+    fn as_metric_value(&self) -> i64 {
+        self.id.to_i128().map_or(i64::MAX, |value| {
+            value.clamp(i64::MIN.into(), i64::MAX.into()) as i64
+        })
+    }
+}
+
+impl<T, I: IntegerBacking> Id<T, I> {
+    /// Iterates from `start` to `end`, both inclusive
+    ///
+    /// Empty if `start > end`. Works on stable Rust, unlike ranges of IDs which would require
+    /// the unstable `Step` trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let ids: Vec<_> = Id::<String, u8>::range_inclusive(Id::new(253), Id::new(255)).collect();
+    /// assert_eq!(ids, [Id::new(253), Id::new(254), Id::new(255)]);
+    /// ```
+    pub fn range_inclusive(start: Self, end: Self) -> impl Iterator<Item = Self> {
+        let end = end.id;
+        let mut next = (start.id <= end).then_some(start.id);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = if current < end {
+                current.checked_add(I::ONE)
+            } else {
+                None
+            };
+            Some(Id::new(current))
+        })
+    }
+
+    /// Encodes the ID so that byte-wise order matches numeric order
+    ///
+    /// For keys of embedded stores like sled or LMDB, which sort keys lexicographically. The
+    /// encoding is as wide as the backing type, so don't mix backings within a keyspace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u16>::new(258).to_sortable_bytes(), [1, 2]);
+    /// assert_eq!(Id::<String, i8>::new(-1).to_sortable_bytes(), [0x7f]);
+    /// assert!(Id::<String, i8>::new(-1).to_sortable_bytes() < Id::<String, i8>::new(0).to_sortable_bytes());
+    /// ```
+    pub fn to_sortable_bytes(&self) -> Vec<u8> {
+        self.id.to_sortable_bytes()
+    }
+
+    /// Whether every value of this ID's backing type is representable in `I2`
+    ///
+    /// Sizes of `usize` & `isize` are those of the compilation target. See
+    /// [`assert_backing_fits!`](crate::assert_backing_fits) to check this at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert!(Id::<String, u32>::fits_in::<i64>());
+    /// assert!(!Id::<String, u32>::fits_in::<i32>());
+    /// assert!(!Id::<String, i8>::fits_in::<u64>());
+    /// ```
+    pub const fn fits_in<I2: IntegerBacking>() -> bool {
+        match (I::SIGNED, I2::SIGNED) {
+            (false, true) => I2::BITS > I::BITS,
+            (true, false) => false,
+            _ => I2::BITS >= I::BITS,
+        }
+    }
+
+    /// Reinterprets the backing's bits as another integer type of the same width
+    ///
+    /// This is not a value conversion: negative signed values become large unsigned ones & vice
+    /// versa. `usize` & `isize` only match the fixed-width types of the target's pointer width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, u32>::new(u32::MAX);
+    /// assert_eq!(id.bitcast_backing::<i32>(), Id::new(-1));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use typed_id::Id;
+    /// let id = Id::<String, u32>::new(1);
+    /// id.bitcast_backing::<i64>(); // different widths
+    /// ```
+    pub fn bitcast_backing<I2: IntegerBacking<Bytes = I::Bytes>>(self) -> Id<T, I2> {
+        Id::new(I2::from_ne_bytes(self.id.to_ne_bytes()))
+    }
+
+    /// The backing as a plain integer, for scalar fields of schema-based formats like
+    /// FlatBuffers or Cap'n Proto
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, u64>::new(9);
+    /// let field: u64 = id.into_scalar();
+    /// assert_eq!(Id::<String, u64>::from_scalar(field), id);
+    /// ```
+    pub const fn into_scalar(self) -> I {
+        self.id
+    }
+
+    /// Wraps a plain integer read from a scalar field, see [`Id::into_scalar`]
+    pub const fn from_scalar(scalar: I) -> Self {
+        Id::new(scalar)
+    }
+
+    /// The backing converted to `I2`, clamped to `I2`'s range if it doesn't fit
+    ///
+    /// For display in narrow fields where clamping is acceptable. Unlike
+    /// [`Id::change_backing_type`] this returns a plain value, as a clamped value no longer
+    /// identifies anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u64>::new(70_000).value_as::<u16>(), u16::MAX);
+    /// assert_eq!(Id::<String, i32>::new(-5).value_as::<u8>(), 0);
+    /// assert_eq!(Id::<String, u64>::new(42).value_as::<i8>(), 42);
+    /// ```
+    pub fn value_as<I2: IntegerBacking>(&self) -> I2 {
+        match self.id.to_i128() {
+            Some(value) => {
+                I2::from_i128(value).unwrap_or(if value < 0 { I2::MIN } else { I2::MAX })
+            }
+            // only u128 values above i128::MAX don't fit in an i128
+            None => I2::MAX,
+        }
+    }
+
+    /// `other - self` as a signed distance, so unsigned IDs can step backwards without
+    /// underflowing
+    ///
+    /// # Panics
+    ///
+    /// If either value or the distance doesn't fit in an `i128`, which only happens for
+    /// 128 bit backings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let (a, b) = (Id::<String, u64>::new(3), Id::<String, u64>::new(10));
+    /// assert_eq!(a.signed_distance_to(&b), 7);
+    /// assert_eq!(b.signed_distance_to(&a), -7);
+    /// ```
+    pub fn signed_distance_to(&self, other: &Self) -> i128 {
+        other
+            .id
+            .to_i128()
+            .zip(self.id.to_i128())
+            .and_then(|(other, this)| other.checked_sub(this))
+            .expect("distance between IDs overflows an i128")
+    }
+}
+
+impl<T, I> Id<T, I>
+where
+    I: IntegerBacking,
+    I::Err: Display,
+{
+    /// Parses an ID like `FromStr`, also rejecting values above `max`
+    ///
+    /// For APIs that cap the IDs they accept from clients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, ParseIdErrorKind};
+    /// assert_eq!(Id::<String>::from_str_max("1000", 1000), Ok(Id::new(1000)));
+    ///
+    /// let err = Id::<String>::from_str_max("1001", 1000).unwrap_err();
+    /// assert_eq!(err.kind(), &ParseIdErrorKind::AboveMax("1000".to_string()));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"invalid alloc::string::String id "1001": value above maximum 1000"#
+    /// );
+    /// ```
+    pub fn from_str_max(s: &str, max: I) -> Result<Self, ParseIdError> {
+        let id: Self = s.parse()?;
+        if id.id > max {
+            return Err(ParseIdError::new(
+                s,
+                type_name::<T>(),
+                ParseIdErrorKind::AboveMax(max.to_string()),
+            ));
+        }
+        Ok(id)
+    }
+
+    /// Parses an ID like `FromStr`, also rejecting zero
+    ///
+    /// For IDs that start at 1, or that will be converted to a `NonZero` backing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, ParseIdErrorKind};
+    /// assert_eq!(Id::<String>::from_str_nonzero("1"), Ok(Id::new(1)));
+    ///
+    /// let err = Id::<String>::from_str_nonzero("0").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseIdErrorKind::Zero);
+    /// assert_eq!(err.to_string(), r#"invalid alloc::string::String id "0": id must be nonzero"#);
+    /// ```
+    pub fn from_str_nonzero(s: &str) -> Result<Self, ParseIdError> {
+        let id: Self = s.parse()?;
+        if id.id == I::ZERO {
+            return Err(ParseIdError::new(
+                s,
+                type_name::<T>(),
+                ParseIdErrorKind::Zero,
+            ));
+        }
+        Ok(id)
+    }
+}
+
+impl<T, I: IntegerBacking + BitXor<Output = I>> Id<T, I> {
+    /// XORs the value with `key`, hiding sequential IDs from casual enumeration
+    ///
+    /// This is not security: one known ID & its obfuscated form reveal the key. Use a different
+    /// key per owner type so equal values of different owners don't match. See
+    /// `Id::to_hashid`, behind the `hashids` feature, for a less transparent encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// const KEY: u32 = 0x5f3c_9a21;
+    /// let id = Id::<String>::new(7);
+    /// let public = id.obfuscate(KEY);
+    /// assert_ne!(public, id);
+    /// assert_eq!(public.deobfuscate(KEY), id);
+    /// ```
+    pub fn obfuscate(&self, key: I) -> Self {
+        Id::new(self.id ^ key)
+    }
+
+    /// Reverses [`Id::obfuscate`] with the same key
+    pub fn deobfuscate(&self, key: I) -> Self {
+        Id::new(self.id ^ key)
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use crate::Id;
+
+/// Deduplicates string backings of IDs for an owner type T
+///
+/// Every ID interned from equal strings shares one `Arc<str>` allocation, which saves memory
+/// when the same string IDs repeat across many records.
+///
+/// Examples:
+///
+/// ```
+/// use std::sync::Arc;
+/// use typed_id::IdInterner;
+/// let mut interner = IdInterner::<String>::new();
+/// let a = interner.intern("usr_alice");
+/// let b = interner.intern(&String::from("usr_alice"));
+///
+/// assert_eq!(a, b);
+/// assert!(Arc::ptr_eq(&a.into_inner(), &b.into_inner()));
+/// assert_eq!(interner.len(), 1);
+/// ```
+pub struct IdInterner<T> {
+    strings: HashSet<Arc<str>>,
+    t: PhantomData<fn() -> T>,
+}
+
+impl<T> IdInterner<T> {
+    pub fn new() -> Self {
+        Self {
+            strings: HashSet::new(),
+            t: PhantomData,
+        }
+    }
+
+    /// Returns an ID backed by the shared copy of `s`, allocating one if `s` is new
+    pub fn intern(&mut self, s: &str) -> Id<T, Arc<str>> {
+        if let Some(shared) = self.strings.get(s) {
+            return Id::new(shared.clone());
+        }
+        let shared: Arc<str> = Arc::from(s);
+        self.strings.insert(shared.clone());
+        Id::new(shared)
+    }
+
+    /// Number of distinct strings interned
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+impl<T> Default for IdInterner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for IdInterner<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IdInterner")
+            .field("len", &self.strings.len())
+            .finish()
+    }
+}
+
+impl<T> Clone for IdInterner<T> {
+    fn clone(&self) -> Self {
+        Self {
+            strings: self.strings.clone(),
+            t: PhantomData,
+        }
+    }
+}
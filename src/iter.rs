@@ -0,0 +1,25 @@
+use crate::Id;
+
+/// Changes the owner or backing type of every ID in an iterator
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, IdIteratorExt};
+/// let ids = vec![Id::<String, u32>::new(1), Id::new(2)];
+/// let wide: Vec<Id<String, u64>> = ids.into_iter().change_all_backing().collect();
+/// assert_eq!(wide, [Id::new(1), Id::new(2)]);
+/// ```
+pub trait IdIteratorExt<T, I>: Iterator<Item = Id<T, I>> + Sized {
+    /// [`Id::change_backing_type`] for each ID
+    fn change_all_backing<I2: From<I>>(self) -> impl Iterator<Item = Id<T, I2>> {
+        self.map(Id::change_backing_type)
+    }
+
+    /// [`Id::change_owner_type`] for each ID
+    fn change_all_owner<T2>(self) -> impl Iterator<Item = Id<T2, I>> {
+        self.map(Id::change_owner_type)
+    }
+}
+
+impl<T, I, It: Iterator<Item = Id<T, I>>> IdIteratorExt<T, I> for It {}
@@ -1,14 +1,82 @@
 use std::{
-    any::type_name,
-    cmp::Ordering,
+    any::{TypeId, type_name},
+    cmp::{Ordering, Reverse},
     convert::{From, Into},
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::RangeInclusive,
+    str::FromStr,
 };
 
+mod btree_map;
+mod bytes;
+mod checksum;
+mod clock;
+mod composite;
+mod convert;
+mod display;
+mod dyn_id;
+mod env;
+mod erased;
+mod error;
+mod fixed_str;
+mod format;
+mod generator;
+mod hash;
+#[cfg(feature = "hashids")]
+mod hashids;
+mod hex;
+mod integer;
+mod interner;
+mod iter;
+mod macros;
+mod namespaced;
+#[cfg(feature = "nanoid")]
+mod nanoid;
+#[cfg(all(feature = "nanoid", not(unix)))]
+compile_error!("the `nanoid` feature reads /dev/urandom, so it's only available on Unix targets");
+mod net;
+mod nonzero;
+mod public_id;
+mod scalar;
+mod secret;
+mod sentinel;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+mod set;
+mod snowflake;
+mod strict;
+mod timestamp;
+mod url;
+mod varint;
+mod vec;
+mod wrapping;
+
+pub use btree_map::TypedIdBTreeMap;
+pub use clock::{Clock, MockClock, SystemClock};
+pub use composite::CompositeId;
+pub use convert::{ConvertOwner, IntoId};
+pub use display::{DisplayStr, OptionIdDisplay, QuotedId, TruncatedId, display_or};
+pub use dyn_id::DynId;
+pub use erased::ErasedId;
+pub use error::{FromEnvError, ParseIdError, ParseIdErrorKind, VarintError};
+pub use fixed_str::FixedStr;
+pub use format::{Base62, Decimal, FormattedId, Hex, IdFormat, Prefixed};
+pub use generator::{AtomicIdGenerator, IdGenerator};
+pub use hash::StableBytes;
+pub use integer::{AsMetricValue, IntegerBacking, UnsignedBacking};
+pub use interner::IdInterner;
+pub use iter::IdIteratorExt;
+pub use namespaced::NamespacedId;
+pub use public_id::IdPrefix;
+pub use secret::SecretId;
+pub use sentinel::Sentinel;
+pub use set::IdSet;
+pub use snowflake::SnowflakeGenerator;
+pub use strict::StrictBacking;
+pub use timestamp::TimestampLayout;
+pub use vec::IdVec;
 
 type DefaultIdType = u32;
 
@@ -39,9 +107,21 @@ type DefaultIdType = u32;
 /// let id = Id::<&str>::new(1);
 /// do_thing(id); // cannot pass argument
 /// ```
+///
+/// The owner type is only a marker, so `Id<T, I>` is `Send`, `Sync` & `Unpin` exactly when I is.
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use typed_id::Id;
+/// fn assert_send<S: Send>() {}
+/// assert_send::<Id<String, Rc<str>>>(); // Rc isn't Send
+/// ```
+///
+/// `Id<T, I>` is `repr(transparent)`, so it has the same layout as I for every T.
+#[repr(transparent)]
 pub struct Id<T, I = DefaultIdType> {
     id: I,
-    t: PhantomData<T>,
+    t: PhantomData<fn() -> T>,
 }
 
 impl<T, I> Id<T, I> {
@@ -54,10 +134,23 @@ impl<T, I> Id<T, I> {
     ///
     /// let id = Id::<MyType>::new(1);
     /// ```
-    pub fn new(id: I) -> Id<T, I> {
+    pub const fn new(id: I) -> Id<T, I> {
         Id::<T, I> { id, t: PhantomData }
     }
 
+    /// Consumes the ID, returning the backing value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<bool, String>::new("alice".to_string());
+    /// assert_eq!(id.into_inner(), "alice");
+    /// ```
+    pub fn into_inner(self) -> I {
+        self.id
+    }
+
     /// # Examples
     ///
     /// ```
@@ -92,6 +185,33 @@ impl<T, I> Id<T, I> {
         type_name::<T>()
     }
 
+    /// The owner & backing type names, as [`Id::owner_type`] & [`Id::backing_type`] return
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<bool, i64>::new(1).type_names(), ("bool", "i64"));
+    /// ```
+    pub fn type_names(&self) -> (&'static str, &'static str) {
+        (type_name::<T>(), type_name::<I>())
+    }
+
+    /// The owner type's name without its module path or generic parameters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::new(1).short_owner_type(), "String");
+    /// assert_eq!(Id::<Vec<String>>::new(1).short_owner_type(), "Vec");
+    /// ```
+    pub fn short_owner_type(&self) -> &'static str {
+        let name = type_name::<T>();
+        let name = name.split_once('<').map_or(name, |(path, _)| path);
+        name.rsplit_once("::").map_or(name, |(_, last)| last)
+    }
+
     /// # Examples
     ///
     /// ```
@@ -144,6 +264,182 @@ impl<T, I> Id<T, I> {
     ) -> Result<Id<T, I2>, <I as TryInto<I2>>::Error> {
         Ok(Id::<T, I2>::new(self.id.try_into()?))
     }
+
+    /// Converts to a backing that can hold every value of this one, like `u32` to `u64`
+    ///
+    /// Same as [`Id::change_backing_type`], named for intent at call sites.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<bool, u32>::new(7);
+    /// assert_eq!(id.widen::<u64>(), Id::<bool, u64>::new(7));
+    /// ```
+    pub fn widen<I2: From<I>>(self) -> Id<T, I2> {
+        Id::new(self.id.into())
+    }
+
+    /// Converts to a backing that may not hold this ID's value, like `u64` to `u32`
+    ///
+    /// Same as [`Id::try_change_backing_type`], named for intent at call sites.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<bool, u64>::new(7).narrow::<u32>(), Ok(Id::new(7)));
+    /// assert!(Id::<bool, u64>::new(u64::MAX).narrow::<u32>().is_err());
+    /// ```
+    pub fn narrow<I2: TryFrom<I>>(self) -> Result<Id<T, I2>, I2::Error> {
+        self.id.try_into().map(Id::new)
+    }
+
+    /// Views the ID as an ID of owner T2 without moving or copying it
+    ///
+    /// A by-reference [`Id::change_owner_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<bool, String>::new("abc".to_string());
+    /// let view: &Id<u8, String> = id.reinterpret_ref();
+    /// assert_eq!(view.value(), "abc");
+    /// ```
+    pub fn reinterpret_ref<T2>(&self) -> &Id<T2, I> {
+        // SAFETY: Id is repr(transparent) over I, as its only other field is a PhantomData,
+        // which is zero-sized with alignment 1 whatever its type parameter. So `Id<T, I>` &
+        // `Id<T2, I>` both have exactly I's layout & validity, the borrow keeps self's lifetime,
+        // & the owner type carries no data or invariants that could be violated.
+        unsafe { &*(self as *const Self).cast::<Id<T2, I>>() }
+    }
+
+    /// Fallibly transforms the backing, keeping the owner type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<bool, &str>::new("42");
+    /// assert_eq!(id.try_map(str::parse::<u64>), Ok(Id::<bool, u64>::new(42)));
+    ///
+    /// let bad = Id::<bool, &str>::new("forty-two");
+    /// assert!(bad.try_map(str::parse::<u64>).is_err());
+    /// ```
+    pub fn try_map<I2, E, F: FnOnce(I) -> Result<I2, E>>(self, f: F) -> Result<Id<T, I2>, E> {
+        f(self.id).map(Id::new)
+    }
+}
+
+impl<T: 'static, I: 'static> Id<T, I> {
+    /// # Examples
+    ///
+    /// ```
+    /// use std::any::TypeId;
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(1);
+    /// assert_eq!(id.owner_type_id(), TypeId::of::<String>());
+    /// assert_ne!(id.owner_type_id(), Id::<bool>::new(1).owner_type_id());
+    /// ```
+    pub fn owner_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use std::any::TypeId;
+    /// use typed_id::Id;
+    /// let id = Id::<String, i64>::new(1);
+    /// assert_eq!(id.backing_type_id(), TypeId::of::<i64>());
+    /// ```
+    pub fn backing_type_id(&self) -> TypeId {
+        TypeId::of::<I>()
+    }
+}
+
+impl<T, I: PartialOrd> Id<T, I> {
+    /// Creates an ID only if it falls within `range`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::new_in_range(5, 1..=10), Some(Id::new(5)));
+    /// assert_eq!(Id::<String>::new_in_range(0, 1..=10), None);
+    /// ```
+    pub fn new_in_range(id: I, range: RangeInclusive<I>) -> Option<Id<T, I>> {
+        range.contains(&id).then(|| Id::new(id))
+    }
+
+    /// Compares against a raw backing value, for bounds checks like `id < limit`
+    ///
+    /// An inherent method rather than `PartialOrd<I>`, which would stop `id == 5.into()` &
+    /// similar comparisons from inferring their right-hand side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(5);
+    /// assert!(id.partial_cmp_value(&10).is_some_and(|o| o.is_lt()));
+    /// assert!(id.partial_cmp_value(&5).is_some_and(|o| o.is_ge()));
+    /// ```
+    pub fn partial_cmp_value(&self, other: &I) -> Option<Ordering> {
+        self.id.partial_cmp(other)
+    }
+}
+
+impl<T, I: PartialEq> Id<T, I> {
+    /// Whether the backing equals the raw value `other`, see [`Id::partial_cmp_value`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(5);
+    /// assert!(id.eq_value(&5));
+    /// assert!(!id.eq_value(&6));
+    /// ```
+    pub fn eq_value(&self, other: &I) -> bool {
+        self.id == *other
+    }
+
+    /// Whether `other` holds this ID's backing value, for comparing against optional columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new(5);
+    /// assert!(id.eq_opt(Some(&5)));
+    /// assert!(!id.eq_opt(Some(&6)));
+    /// assert!(!id.eq_opt(None));
+    /// ```
+    pub fn eq_opt(&self, other: Option<&I>) -> bool {
+        other.is_some_and(|other| self.id == *other)
+    }
+}
+
+impl<T, I: StrictBacking> Id<T, I> {
+    /// Like [`Id::new`], but only accepts backing types implementing [`StrictBacking`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::new_strict(1);
+    /// let id_str = Id::<String, &str>::new_strict("alice");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use typed_id::Id;
+    /// let id = Id::<String, f64>::new_strict(1.5); // floats aren't strict backings
+    /// ```
+    pub fn new_strict(id: I) -> Id<T, I> {
+        Id::new(id)
+    }
 }
 
 impl<T, I> Id<T, I>
@@ -168,10 +464,138 @@ where
     }
 }
 
+impl<T, I: Ord + Clone> Id<T, I> {
+    /// Sort key ordering IDs descending, e.g. newest first for time-ordered IDs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let mut ids = [Id::<String>::new(2), Id::new(3), Id::new(1)];
+    /// ids.sort_by_key(Id::desc_key);
+    /// assert_eq!(ids, [Id::new(3), Id::new(2), Id::new(1)]);
+    /// ```
+    pub fn desc_key(&self) -> Reverse<Self> {
+        Reverse(self.clone())
+    }
+}
+
+impl<T, I> Id<T, I>
+where
+    I: Copy,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, u64>::new(9);
+    /// assert_eq!(id.copied(), 9);
+    /// ```
+    pub fn copied(&self) -> I {
+        self.id
+    }
+
+    /// Const equivalent of [`Id::copied`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// const ID: Id<String> = Id::new(3);
+    /// const VALUE: u32 = ID.get();
+    /// assert_eq!(VALUE, 3);
+    /// ```
+    pub const fn get(&self) -> I {
+        self.id
+    }
+}
+
+impl<T, I: Copy + TryInto<usize>> Id<T, I> {
+    /// Converts the backing to a `usize`, for indexing slices
+    ///
+    /// # Panics
+    ///
+    /// If the backing value doesn't fit in a `usize`. See [`Id::checked_as_usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let names = ["alice", "bob"];
+    /// let id = Id::<String, u8>::new(1);
+    /// assert_eq!(names[id.as_usize()], "bob");
+    /// ```
+    pub fn as_usize(&self) -> usize {
+        self.checked_as_usize()
+            .expect("ID backing value does not fit in usize")
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, i32>::new(3).checked_as_usize(), Some(3));
+    /// assert_eq!(Id::<String, i32>::new(-3).checked_as_usize(), None);
+    /// ```
+    pub fn checked_as_usize(&self) -> Option<usize> {
+        self.id.try_into().ok()
+    }
+}
+
+impl<T, I: TryFrom<usize>> Id<T, I> {
+    /// Creates an ID from a `usize`, such as a slice index
+    ///
+    /// # Panics
+    ///
+    /// If the index doesn't fit in the backing type. See [`Id::checked_from_usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String>::from_usize(4);
+    /// assert_eq!(id, Id::new(4));
+    /// ```
+    pub fn from_usize(index: usize) -> Self {
+        Self::checked_from_usize(index).expect("usize does not fit in ID backing type")
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u8>::checked_from_usize(255), Some(Id::new(255)));
+    /// assert_eq!(Id::<String, u8>::checked_from_usize(256), None);
+    /// ```
+    pub fn checked_from_usize(index: usize) -> Option<Self> {
+        index.try_into().ok().map(Self::new)
+    }
+}
+
 pub trait HasId<T = Self, I = DefaultIdType> {
     fn id(&self) -> Id<T, I>;
 }
 
+/// Declares the backing type an owner's IDs use, for use with [`IdOf`]
+///
+/// ```
+/// use typed_id::{Id, IdBacking, IdOf};
+///
+/// struct User;
+/// impl IdBacking for User {
+///     type Backing = u64;
+/// }
+///
+/// type UserId = IdOf<User>;
+/// let id: Id<User, u64> = UserId::new(u64::MAX);
+/// ```
+pub trait IdBacking {
+    type Backing;
+}
+
+/// ID bound to an owner type T & backed by T's [`IdBacking::Backing`]
+pub type IdOf<T> = Id<T, <T as IdBacking>::Backing>;
+
 impl<T, I: Default> Default for Id<T, I> {
     fn default() -> Self {
         Self {
@@ -187,9 +611,75 @@ impl<T, I> From<I> for Id<T, I> {
     }
 }
 
+/// Forwards to the backing's `Display`, including formatter flags
+///
+/// ```
+/// use typed_id::Id;
+/// let id = Id::<String, i64>::new(7);
+/// assert_eq!(format!("{:+}", id), "+7");
+/// assert_eq!(format!("{:03}", id), "007");
+/// ```
 impl<T, I: Display> Display for Id<T, I> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.id.fmt(f)
+        Display::fmt(&self.id, f)
+    }
+}
+
+/// Parses the backing value, reporting failures as a [`ParseIdError`]
+///
+/// ```
+/// use typed_id::Id;
+/// let id: Id<String> = "5".parse().unwrap();
+/// assert_eq!(id, Id::new(5));
+/// assert!("-5".parse::<Id<String>>().is_err());
+/// ```
+impl<T, I> FromStr for Id<T, I>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::new).map_err(|e: I::Err| {
+            ParseIdError::new(
+                s,
+                type_name::<T>(),
+                ParseIdErrorKind::Backing(e.to_string()),
+            )
+        })
+    }
+}
+
+impl<T, I> Id<T, I>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    /// Parses a `sep`-separated list of IDs, like the `1,2,3` of a `?ids=1,2,3` query
+    ///
+    /// An empty string is an empty list. Elements aren't trimmed. On failure the error's
+    /// [`index`](ParseIdError::index) is the position of the first bad element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let ids = Id::<String>::parse_list("1,2,3", ',').unwrap();
+    /// assert_eq!(ids, [Id::new(1), Id::new(2), Id::new(3)]);
+    ///
+    /// let err = Id::<String>::parse_list("1,x,3", ',').unwrap_err();
+    /// assert_eq!((err.input(), err.index()), ("x", Some(1)));
+    /// ```
+    pub fn parse_list(s: &str, sep: char) -> Result<Vec<Self>, ParseIdError> {
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        s.split(sep)
+            .enumerate()
+            .map(|(index, element)| element.parse().map_err(|e: ParseIdError| e.at_index(index)))
+            .collect()
     }
 }
 
@@ -205,10 +695,23 @@ impl<T, I: Debug> Debug for Id<T, I> {
     }
 }
 
+/// `clone_from`, & so `ToOwned::clone_into`, forward to the backing's, reusing a string
+/// backing's allocation
+///
+/// ```
+/// use typed_id::Id;
+/// let mut target = Id::<String, String>::new(String::with_capacity(64));
+/// Id::new("usr_1".to_string()).clone_into(&mut target);
+/// assert_eq!(target, Id::new("usr_1".to_string()));
+/// ```
 impl<T, I: Clone> Clone for Id<T, I> {
     fn clone(&self) -> Self {
         Self::new(self.id.clone())
     }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.id.clone_from(&source.id);
+    }
 }
 
 impl<T, I: Copy> Copy for Id<T, I> {}
@@ -238,6 +741,3 @@ impl<T, I: Hash> Hash for Id<T, I> {
         self.id.hash(state);
     }
 }
-
-unsafe impl<T, I: Send> Send for Id<T, I> {}
-unsafe impl<T, I: Sync> Sync for Id<T, I> {}
@@ -0,0 +1,301 @@
+/// Generates `From` conversions between a single-field tuple newtype & an [`Id`](crate::Id)
+///
+/// Eases migrating hand-rolled ID newtypes to this crate.
+///
+/// ```
+/// use typed_id::{Id, bridge_id};
+///
+/// struct User;
+/// struct UserId(u32);
+/// bridge_id!(UserId => User, u32);
+///
+/// let id: Id<User> = UserId(5).into();
+/// assert_eq!(id, Id::new(5));
+/// let legacy: UserId = id.into();
+/// assert_eq!(legacy.0, 5);
+/// ```
+#[macro_export]
+macro_rules! bridge_id {
+    ($newtype:ty => $owner:ty, $backing:ty) => {
+        impl ::core::convert::From<$newtype> for $crate::Id<$owner, $backing> {
+            fn from(value: $newtype) -> Self {
+                $crate::Id::new(value.0)
+            }
+        }
+
+        impl ::core::convert::From<$crate::Id<$owner, $backing>> for $newtype {
+            fn from(id: $crate::Id<$owner, $backing>) -> Self {
+                Self(id.into_inner())
+            }
+        }
+    };
+}
+
+/// Generates an enum holding an [`Id`](crate::Id) of any one of a fixed set of owners
+///
+/// Each variant is named after its owner type & wraps an ID with the given backing. The enum
+/// derives `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` & `Hash`, displays as
+/// `Variant(id)`, & has a `From` impl for each wrapped ID type.
+///
+/// IDs order by variant in declaration order first, then by value, so the enum can key a
+/// `BTreeMap`.
+///
+/// ```
+/// use typed_id::{Id, id_enum};
+///
+/// struct User;
+/// struct Post;
+/// id_enum! {
+///     pub AnyId { User(u32), Post(u64) }
+/// }
+///
+/// let id: AnyId = Id::<Post, u64>::new(7).into();
+/// assert_eq!(id.to_string(), "Post(7)");
+/// match id {
+///     AnyId::User(user) => panic!("unexpected user {user}"),
+///     AnyId::Post(post) => assert_eq!(post, Id::new(7)),
+/// }
+/// assert!(AnyId::from(Id::<User>::new(100)) < AnyId::from(Id::<Post, u64>::new(1)));
+/// ```
+#[macro_export]
+macro_rules! id_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident { $($owner:ident($backing:ty)),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis enum $name {
+            $($owner($crate::Id<$owner, $backing>),)+
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$owner(id) => ::core::write!(f, "{}({})", stringify!($owner), id),)+
+                }
+            }
+        }
+
+        $(
+            impl ::core::convert::From<$crate::Id<$owner, $backing>> for $name {
+                fn from(id: $crate::Id<$owner, $backing>) -> Self {
+                    Self::$owner(id)
+                }
+            }
+        )+
+    };
+}
+
+/// Fails compilation unless every value of the first integer backing fits in the second
+///
+/// Guards [`change_backing_type`](crate::Id::change_backing_type) & friends against
+/// migrations that would become lossy if a backing is later narrowed. See
+/// [`Id::fits_in`](crate::Id::fits_in).
+///
+/// ```
+/// use typed_id::assert_backing_fits;
+/// assert_backing_fits!(u32 => u64);
+/// assert_backing_fits!(u32 => i64);
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::assert_backing_fits;
+/// assert_backing_fits!(i64 => u64);
+/// ```
+#[macro_export]
+macro_rules! assert_backing_fits {
+    ($from:ty => $to:ty) => {
+        const _: () = ::core::assert!(
+            $crate::Id::<(), $from>::fits_in::<$to>(),
+            ::core::concat!(
+                "backing ",
+                ::core::stringify!($from),
+                " does not fit in ",
+                ::core::stringify!($to)
+            )
+        );
+    };
+}
+
+/// Fails compilation unless the type's [`HasId`](crate::HasId) impl returns IDs it owns
+///
+/// Catches fields like `id: Id<OtherType>` behind a `HasId` impl. The backing can be given as
+/// a second argument when the type implements `HasId` for several backings.
+///
+/// ```
+/// use typed_id::{HasId, Id, assert_self_id};
+///
+/// struct User {
+///     id: Id<User, u64>,
+/// }
+/// impl HasId<User, u64> for User {
+///     fn id(&self) -> Id<User, u64> {
+///         self.id
+///     }
+/// }
+///
+/// assert_self_id!(User);
+/// assert_self_id!(User, u64);
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{HasId, Id, assert_self_id};
+///
+/// struct Post;
+/// struct User {
+///     id: Id<Post>,
+/// }
+/// impl HasId<Post> for User {
+///     fn id(&self) -> Id<Post> {
+///         self.id
+///     }
+/// }
+///
+/// assert_self_id!(User);
+/// ```
+#[macro_export]
+macro_rules! assert_self_id {
+    ($owner:ty) => {
+        $crate::assert_self_id!($owner, _);
+    };
+    ($owner:ty, $backing:ty) => {
+        const _: fn() = || {
+            fn owns_its_id<T: $crate::HasId<T, I>, I>() {}
+            owns_its_id::<$owner, $backing>();
+        };
+    };
+}
+
+/// Fails compilation unless an ID type has the given size in bytes
+///
+/// `Id` is `repr(transparent)`, so this locks in its backing's size, e.g. where IDs cross an
+/// FFI boundary.
+///
+/// ```
+/// use typed_id::{Id, assert_id_size};
+/// struct User;
+/// assert_id_size!(Id<User, u32>, 4);
+/// assert_id_size!(Id<User, [u8; 16]>, 16);
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, assert_id_size};
+/// struct User;
+/// assert_id_size!(Id<User, u64>, 4);
+/// ```
+#[macro_export]
+macro_rules! assert_id_size {
+    ($id:ty, $size:expr) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$id>() == $size,
+            ::core::concat!(
+                ::core::stringify!($id),
+                " is not ",
+                ::core::stringify!($size),
+                " bytes"
+            )
+        );
+    };
+}
+
+/// Asserts two IDs are equal, panicking with a concise `OwnerId mismatch: 5 != 7` message
+///
+/// The IDs are shown with `Display` & the [short owner name](crate::Id::short_owner_type),
+/// rather than the verbose `Debug` output of `assert_eq!`. Extra arguments are formatted into
+/// the message like `assert_eq!`'s.
+///
+/// ```
+/// use typed_id::{Id, assert_id_eq};
+/// struct User;
+/// assert_id_eq!(Id::<User>::new(5), Id::new(5));
+/// ```
+///
+/// ```should_panic
+/// # use typed_id::{Id, assert_id_eq};
+/// # struct User;
+/// // panics with "UserId mismatch: 5 != 7"
+/// assert_id_eq!(Id::<User>::new(5), Id::new(7));
+/// ```
+#[macro_export]
+macro_rules! assert_id_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    ::core::panic!(
+                        "{}Id mismatch: {} != {}",
+                        left.short_owner_type(),
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    ::core::panic!(
+                        "{}Id mismatch: {} != {}: {}",
+                        left.short_owner_type(),
+                        left,
+                        right,
+                        ::core::format_args!($($arg)+)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Declares ID type aliases, registering each owner's backing as its canonical
+/// [`IdBacking`](crate::IdBacking)
+///
+/// Declaring a second alias for the same owner anywhere in the crate fails to compile with
+/// conflicting `IdBacking` impls, so every alias agrees on one backing. Owners must be local
+/// types.
+///
+/// ```
+/// use typed_id::{Id, IdOf, id_alias};
+///
+/// struct User;
+/// struct Order;
+/// id_alias! {
+///     pub type UserId = Id<User, u32>;
+///     pub type OrderId = Id<Order, u64>;
+/// }
+///
+/// let id: IdOf<User> = UserId::new(5);
+/// assert_eq!(id, Id::<User, u32>::new(5));
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, id_alias};
+///
+/// struct User;
+/// id_alias! {
+///     pub type UserId = Id<User, u32>;
+/// }
+///
+/// mod billing {
+///     use typed_id::{Id, id_alias};
+///     id_alias! {
+///         pub type UserId = Id<super::User, u64>;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! id_alias {
+    ($($(#[$meta:meta])* $vis:vis type $alias:ident = Id<$owner:ty, $backing:ty>;)+) => {
+        $(
+            $(#[$meta])*
+            $vis type $alias = $crate::Id<$owner, $backing>;
+
+            impl $crate::IdBacking for $owner {
+                type Backing = $backing;
+            }
+        )+
+    };
+}
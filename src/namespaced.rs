@@ -0,0 +1,114 @@
+use std::{
+    any::type_name,
+    fmt::{Debug, Display, Formatter, Write},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{
+    DefaultIdType, Id, ParseIdError, ParseIdErrorKind,
+    composite::{EscapeSeparator, unescape_separator},
+};
+
+/// [`Id`] paired with a namespace only known at runtime, such as a tenant
+///
+/// Displays as `{namespace}:{id}` & parses back by splitting on the first `:`. Any `:` or `%`
+/// in the namespace is percent-escaped, as in [`CompositeId`](crate::CompositeId), so every
+/// namespace round-trips.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, NamespacedId};
+/// let id = NamespacedId::new("acme", Id::<String>::new(5));
+/// assert_eq!(id.to_string(), "acme:5");
+/// assert_eq!("acme:5".parse(), Ok(id));
+///
+/// let id = NamespacedId::new("a:b", Id::<String, String>::new("c".to_string()));
+/// assert_eq!(id.to_string(), "a%3Ab:c");
+/// assert_eq!("a%3Ab:c".parse(), Ok(id));
+/// ```
+pub struct NamespacedId<T, I = DefaultIdType> {
+    namespace: String,
+    id: Id<T, I>,
+}
+
+impl<T, I> NamespacedId<T, I> {
+    pub fn new(namespace: impl Into<String>, id: Id<T, I>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            id,
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn id(&self) -> &Id<T, I> {
+        &self.id
+    }
+
+    pub fn into_parts(self) -> (String, Id<T, I>) {
+        (self.namespace, self.id)
+    }
+}
+
+impl<T, I: Display> Display for NamespacedId<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        EscapeSeparator(f).write_str(&self.namespace)?;
+        write!(f, ":{}", self.id)
+    }
+}
+
+impl<T, I> FromStr for NamespacedId<T, I>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        let (namespace, id) = s
+            .split_once(':')
+            .ok_or_else(|| error(ParseIdErrorKind::MissingSeparator(':')))?;
+        let namespace =
+            unescape_separator(namespace).ok_or_else(|| error(ParseIdErrorKind::Malformed))?;
+        let id = id
+            .parse()
+            .map_err(|e: I::Err| error(ParseIdErrorKind::Backing(e.to_string())))?;
+
+        Ok(Self::new(namespace, Id::new(id)))
+    }
+}
+
+impl<T, I: Debug> Debug for NamespacedId<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NamespacedId")
+            .field("namespace", &self.namespace)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T, I: Clone> Clone for NamespacedId<T, I> {
+    fn clone(&self) -> Self {
+        Self::new(self.namespace.clone(), self.id.clone())
+    }
+}
+
+impl<T, I: PartialEq> PartialEq for NamespacedId<T, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace == other.namespace && self.id == other.id
+    }
+}
+
+impl<T, I: Eq> Eq for NamespacedId<T, I> {}
+
+impl<T, I: Hash> Hash for NamespacedId<T, I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.namespace.hash(state);
+        self.id.hash(state);
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fs::File, io::Read, sync::OnceLock};
+
+use crate::Id;
+
+const ALPHABET: &[u8; 64] = b"_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DEFAULT_LEN: usize = 21;
+
+/// `/dev/urandom`, opened once & shared by every thread
+static URANDOM: OnceLock<File> = OnceLock::new();
+
+impl<T> Id<T, String> {
+    /// Generates a random, URL-safe 21 character ID using the nanoid alphabet
+    ///
+    /// Randomness comes from the OS CSPRNG via `/dev/urandom`, so IDs are unguessable & safe to
+    /// expose publicly.
+    ///
+    /// # Panics
+    ///
+    /// If `/dev/urandom` can't be read, such as when the process is out of file descriptors.
+    /// See [`Id::try_new_nanoid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, String>::new_nanoid();
+    /// assert_eq!(id.value().len(), 21);
+    /// ```
+    pub fn new_nanoid() -> Self {
+        Self::new_nanoid_of_len(DEFAULT_LEN)
+    }
+
+    /// Like [`Id::new_nanoid`], with `len` characters
+    ///
+    /// # Panics
+    ///
+    /// If `/dev/urandom` can't be read. See [`Id::try_new_nanoid_of_len`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, String>::new_nanoid_of_len(8);
+    /// assert_eq!(id.value().len(), 8);
+    /// ```
+    pub fn new_nanoid_of_len(len: usize) -> Self {
+        Self::try_new_nanoid_of_len(len).expect("failed to read /dev/urandom")
+    }
+
+    /// Like [`Id::new_nanoid`], returning an error if `/dev/urandom` can't be read
+    pub fn try_new_nanoid() -> std::io::Result<Self> {
+        Self::try_new_nanoid_of_len(DEFAULT_LEN)
+    }
+
+    /// Like [`Id::new_nanoid_of_len`], returning an error if `/dev/urandom` can't be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// let id = Id::<String, String>::try_new_nanoid_of_len(8)?;
+    /// assert_eq!(id.value().len(), 8);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn try_new_nanoid_of_len(len: usize) -> std::io::Result<Self> {
+        let mut urandom = match URANDOM.get() {
+            Some(urandom) => urandom,
+            None => {
+                let file = File::open("/dev/urandom")?;
+                // a racing thread's file is dropped, keeping whichever was stored first
+                URANDOM.get_or_init(|| file)
+            }
+        };
+        let mut bytes = vec![0; len];
+        urandom.read_exact(&mut bytes)?;
+
+        // 64 divides 256, so masking to 6 bits keeps every character equally likely
+        let id = bytes
+            .into_iter()
+            .map(|byte| ALPHABET[usize::from(byte & 0x3f)] as char)
+            .collect();
+
+        Ok(Self::new(id))
+    }
+}
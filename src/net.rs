@@ -0,0 +1,39 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::Id;
+
+/// Packs the address's octets big-endian, like `u32::from(ip)`
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use typed_id::Id;
+/// let id = Id::<String, u32>::from(Ipv4Addr::new(10, 0, 0, 1));
+/// assert_eq!(id, Id::new(0x0a00_0001));
+/// assert_eq!(Ipv4Addr::from(id), Ipv4Addr::new(10, 0, 0, 1));
+/// ```
+impl<T> From<Ipv4Addr> for Id<T, u32> {
+    fn from(ip: Ipv4Addr) -> Self {
+        Id::new(ip.into())
+    }
+}
+
+impl<T> From<Id<T, u32>> for Ipv4Addr {
+    fn from(id: Id<T, u32>) -> Self {
+        id.id.into()
+    }
+}
+
+/// Packs the address's segments big-endian, like `u128::from(ip)`
+impl<T> From<Ipv6Addr> for Id<T, u128> {
+    fn from(ip: Ipv6Addr) -> Self {
+        Id::new(ip.into())
+    }
+}
+
+impl<T> From<Id<T, u128>> for Ipv6Addr {
+    fn from(id: Id<T, u128>) -> Self {
+        id.id.into()
+    }
+}
@@ -0,0 +1,31 @@
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+use crate::Id;
+
+macro_rules! nonzero_conversions {
+    ($($nz:ty => $t:ty),* $(,)?) => {
+        $(
+            impl<T> Id<T, $nz> {
+                /// Converts to an ID of the same owner backed by the primitive integer
+                pub fn to_primitive(self) -> Id<T, $t> {
+                    Id::new(self.id.get())
+                }
+
+                /// Converts from an ID backed by the primitive integer, returning `None` for zero
+                pub fn try_from_primitive(id: Id<T, $t>) -> Option<Self> {
+                    <$nz>::new(id.id).map(Id::new)
+                }
+            }
+        )*
+    };
+}
+
+nonzero_conversions! {
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+    NonZeroI128 => i128, NonZeroIsize => isize,
+}
@@ -0,0 +1,126 @@
+use std::any::type_name;
+
+use crate::{Id, ParseIdError, ParseIdErrorKind, UnsignedBacking};
+
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Prefix identifying an owner type in public IDs, like `cus` in `cus_4fR`
+///
+/// ```
+/// use typed_id::{Id, IdPrefix};
+///
+/// struct Customer;
+/// impl IdPrefix for Customer {
+///     const PREFIX: &'static str = "cus";
+/// }
+///
+/// let id = Id::<Customer>::new(1234);
+/// assert_eq!(id.public_id(), "cus_Ju");
+/// ```
+pub trait IdPrefix {
+    const PREFIX: &'static str;
+}
+
+impl<T, I: UnsignedBacking> Id<T, I> {
+    /// Encodes the backing in base62 using the alphabet `0-9A-Za-z`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::new(0).to_base62(), "0");
+    /// assert_eq!(Id::<String>::new(61).to_base62(), "z");
+    /// assert_eq!(Id::<String>::new(62).to_base62(), "10");
+    /// ```
+    pub fn to_base62(&self) -> String {
+        let mut value = self.id.to_u128();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(BASE62[(value % 62) as usize]);
+            value /= 62;
+            if value == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        String::from_utf8(digits).expect("base62 digits are ASCII")
+    }
+
+    /// Decodes a base62 string produced by [`Id::to_base62`]
+    ///
+    /// Leading zeros are rejected, so each ID has exactly one encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String>::from_base62("Ju"), Ok(Id::new(1234)));
+    /// assert!(Id::<String>::from_base62("J-U").is_err());
+    /// assert!(Id::<String>::from_base62("0Ju").is_err());
+    /// assert!(Id::<String, u8>::from_base62("zz").is_err()); // too big for u8
+    /// ```
+    pub fn from_base62(s: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        if s.is_empty() {
+            return Err(error(ParseIdErrorKind::Empty));
+        }
+        if s.len() > 1 && s.starts_with('0') {
+            return Err(error(ParseIdErrorKind::Malformed));
+        }
+
+        let mut value: u128 = 0;
+        for c in s.chars() {
+            let digit = match c {
+                '0'..='9' => c as u128 - '0' as u128,
+                'A'..='Z' => c as u128 - 'A' as u128 + 10,
+                'a'..='z' => c as u128 - 'a' as u128 + 36,
+                _ => return Err(error(ParseIdErrorKind::InvalidDigit(c))),
+            };
+            value = value
+                .checked_mul(62)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or_else(|| error(ParseIdErrorKind::Overflow))?;
+        }
+
+        I::from_u128(value)
+            .map(Id::new)
+            .ok_or_else(|| error(ParseIdErrorKind::Overflow))
+    }
+}
+
+impl<T: IdPrefix, I: UnsignedBacking> Id<T, I> {
+    /// Formats as `{prefix}_{base62}`, using the owner's [`IdPrefix`]
+    pub fn public_id(&self) -> String {
+        format!("{}_{}", T::PREFIX, self.to_base62())
+    }
+
+    /// Parses an ID formatted by [`Id::public_id`], rejecting other owners' prefixes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, IdPrefix};
+    ///
+    /// struct Customer;
+    /// impl IdPrefix for Customer {
+    ///     const PREFIX: &'static str = "cus";
+    /// }
+    ///
+    /// assert_eq!(Id::<Customer>::from_public_id("cus_Ju"), Ok(Id::new(1234)));
+    /// assert!(Id::<Customer>::from_public_id("inv_Ju").is_err());
+    /// ```
+    pub fn from_public_id(s: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(s, type_name::<T>(), kind);
+        let (prefix, encoded) = s
+            .rsplit_once('_')
+            .ok_or_else(|| error(ParseIdErrorKind::MissingSeparator('_')))?;
+        if prefix != T::PREFIX {
+            return Err(error(ParseIdErrorKind::PrefixMismatch {
+                expected: T::PREFIX,
+                found: prefix.to_string(),
+            }));
+        }
+
+        Self::from_base62(encoded).map_err(|e| error(e.kind().clone()))
+    }
+}
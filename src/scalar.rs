@@ -0,0 +1,19 @@
+use crate::Id;
+
+macro_rules! into_scalar {
+    ($($t:ty),* $(,)?) => {
+        $(
+            /// Unwraps the ID into the plain scalar used by wire formats like protobuf
+            impl<T> From<Id<T, $t>> for $t {
+                fn from(id: Id<T, $t>) -> Self {
+                    id.id
+                }
+            }
+        )*
+    };
+}
+
+into_scalar! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+    String,
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{DefaultIdType, Id};
+
+/// [`Id`] that's a secret, like an API key ID, so it's redacted from `Debug` & has no
+/// `Display`
+///
+/// The ID is only reachable through [`SecretId::expose_secret`], making each use explicit &
+/// easy to audit.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, SecretId};
+/// struct ApiKey;
+/// let key = SecretId::new(Id::<ApiKey, u64>::new(0xdead_beef));
+/// assert_eq!(format!("{key:?}"), "SecretId<ApiKey>([REDACTED])");
+/// assert_eq!(key.expose_secret(), &Id::new(0xdead_beef));
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, SecretId};
+/// let key = SecretId::new(Id::<String>::new(5));
+/// println!("{key}");
+/// ```
+pub struct SecretId<T, I = DefaultIdType> {
+    id: Id<T, I>,
+}
+
+impl<T, I> SecretId<T, I> {
+    pub fn new(id: Id<T, I>) -> Self {
+        Self { id }
+    }
+
+    pub fn expose_secret(&self) -> &Id<T, I> {
+        &self.id
+    }
+}
+
+impl<T, I> From<Id<T, I>> for SecretId<T, I> {
+    fn from(id: Id<T, I>) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<T, I> Debug for SecretId<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretId<{}>([REDACTED])", self.id.short_owner_type())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Id;
+
+/// Backing value an owner type T uses to mean "no ID", for systems where that isn't zero
+///
+/// Implemented on the owner, once per backing type, keeping the sentinel separate from
+/// `Id::default()`.
+///
+/// ```
+/// use typed_id::{Id, Sentinel};
+///
+/// struct Account;
+/// impl Sentinel<u32> for Account {
+///     const NONE: u32 = u32::MAX;
+/// }
+///
+/// assert!(Id::<Account>::none().is_none());
+/// assert!(!Id::<Account>::default().is_none());
+/// ```
+pub trait Sentinel<I> {
+    const NONE: I;
+}
+
+impl<T: Sentinel<I>, I> Id<T, I> {
+    /// The ID holding the owner's sentinel value
+    pub const fn none() -> Self {
+        Id::new(T::NONE)
+    }
+}
+
+impl<T: Sentinel<I>, I: PartialEq> Id<T, I> {
+    /// Whether the ID holds the owner's sentinel value
+    pub fn is_none(&self) -> bool {
+        self.id == T::NONE
+    }
+}
@@ -1,6 +1,17 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+//! Transparent serde impls for [`Id`], plus opt-in representations for use with
+//! `#[serde(with = "...")]`
+//!
+//! Since an `Id` serializes as its bare backing value, it composes like that value would: a
+//! `#[serde(transparent)]` newtype around an `Id` is also a bare value, & a struct of `Id`
+//! fields can be `#[serde(flatten)]`ed into another. Flattening the transparent newtype itself
+//! fails at runtime, as serde can only flatten maps & structs; give the `Id` a named field
+//! instead.
 
-use crate::Id;
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use crate::{CompositeId, Id, ParseIdError};
 
 impl<T, I: Serialize> Serialize for Id<T, I> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -14,9 +25,425 @@ impl<'de, T, I: Deserialize<'de>> Deserialize<'de> for Id<T, I> {
     }
 }
 
+impl<T, I: Into<serde_json::Value>> From<Id<T, I>> for serde_json::Value {
+    fn from(id: Id<T, I>) -> Self {
+        id.id.into()
+    }
+}
+
+impl<T, I: Clone + Into<serde_json::Value>> Id<T, I> {
+    /// The ID as a JSON value, a `Number` for numeric backings & a `String` for string ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u64>::new(5).to_json_value(), json!(5));
+    /// assert_eq!(Id::<String, String>::new("a".into()).to_json_value(), json!("a"));
+    /// ```
+    pub fn to_json_value(&self) -> serde_json::Value {
+        self.id.clone().into()
+    }
+}
+
+impl<A: Display, B: Display> Serialize for CompositeId<(A, B)> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, A, B> Deserialize<'de> for CompositeId<(A, B)>
+where
+    A: FromStr<Err = ParseIdError>,
+    B: FromStr<Err = ParseIdError>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Represent an [`Id`] as a newtype struct named `Id`
+///
+/// Self-describing formats like JSON still see the bare backing value, while formats that carry
+/// struct names keep the `Id` name.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::Id;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "typed_id::serde::newtype")]
+///     id: Id<User>,
+/// }
+/// ```
+pub mod newtype {
+    use std::{fmt::Formatter, marker::PhantomData};
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{Error, SeqAccess, Visitor},
+    };
+
+    use crate::Id;
+
+    pub fn serialize<T, I, S>(id: &Id<T, I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Serialize,
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("Id", &id.id)
+    }
+
+    pub fn deserialize<'de, T, I, D>(deserializer: D) -> Result<Id<T, I>, D::Error>
+    where
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct("Id", NewtypeVisitor(PhantomData))
+    }
+
+    struct NewtypeVisitor<T, I>(PhantomData<fn() -> Id<T, I>>);
+
+    impl<'de, T, I: Deserialize<'de>> Visitor<'de> for NewtypeVisitor<T, I> {
+        type Value = Id<T, I>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str("newtype struct Id")
+        }
+
+        fn visit_newtype_struct<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            I::deserialize(deserializer).map(Id::new)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            seq.next_element()?
+                .map(Id::new)
+                .ok_or_else(|| A::Error::invalid_length(0, &self))
+        }
+    }
+}
+
+/// Represent an [`Id`] as a string, using its `Display` & `FromStr` impls
+///
+/// Handy for integer IDs consumed by clients that lose precision on large numbers.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::Id;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "typed_id::serde::as_string")]
+///     id: Id<User, u64>,
+/// }
+///
+/// let user = User { id: Id::new(u64::MAX) };
+/// assert_eq!(serde_json::to_string(&user).unwrap(), r#"{"id":"18446744073709551615"}"#);
+/// ```
+pub mod as_string {
+    use std::{fmt::Display, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use crate::Id;
+
+    pub fn serialize<T, I, S>(id: &Id<T, I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(&id.id)
+    }
+
+    pub fn deserialize<'de, T, I, D>(deserializer: D) -> Result<Id<T, I>, D::Error>
+    where
+        I: FromStr,
+        I::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Represent an [`Id`] as a map tagged with its owner type's name, rejecting other owners
+///
+/// Deserializing an ID tagged with a different owner fails, catching IDs cross-wired between
+/// owners at the boundary. The tag is [`std::any::type_name`] of the owner, which may change
+/// between compiler versions & crate renames, so only use this between builds of one codebase.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::Id;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "typed_id::serde::tagged")]
+///     id: Id<User>,
+/// }
+///
+/// let json = serde_json::to_value(User { id: Id::new(5) }).unwrap();
+/// assert!(json["id"]["owner"].as_str().unwrap().ends_with("::User"));
+/// assert_eq!(json["id"]["id"], 5);
+/// ```
+pub mod tagged {
+    use std::any::type_name;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::Id;
+
+    #[derive(Serialize)]
+    struct TaggedRef<'a, I> {
+        owner: &'static str,
+        id: &'a I,
+    }
+
+    #[derive(Deserialize)]
+    struct Tagged<I> {
+        owner: String,
+        id: I,
+    }
+
+    pub fn serialize<T, I, S>(id: &Id<T, I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Serialize,
+        S: Serializer,
+    {
+        TaggedRef {
+            owner: type_name::<T>(),
+            id: &id.id,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, I, D>(deserializer: D) -> Result<Id<T, I>, D::Error>
+    where
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let tagged = Tagged::<I>::deserialize(deserializer)?;
+        let expected = type_name::<T>();
+        if tagged.owner != expected {
+            return Err(D::Error::custom(format_args!(
+                "expected id of {expected}, found id of {}",
+                tagged.owner
+            )));
+        }
+        Ok(Id::new(tagged.id))
+    }
+}
+
+/// Deserialize an [`Id`] from either its bare value or a single-field object like `{"value": 5}`
+///
+/// For upstream APIs that are inconsistent about wrapping IDs. `deserialize` accepts the key
+/// `value`; for other keys, wrap [`deserialize_with_key`](nested::deserialize_with_key) in a
+/// function of your own. IDs serialize as the bare value. This relies on
+/// `Deserializer::deserialize_any`, so it only works with self-describing formats like JSON.
+///
+/// ```
+/// use serde::{Deserialize, Deserializer};
+/// use typed_id::Id;
+///
+/// #[derive(Deserialize)]
+/// struct Order {
+///     #[serde(with = "typed_id::serde::nested")]
+///     id: Id<Order>,
+///     #[serde(deserialize_with = "customer_key")]
+///     customer: Id<String>,
+/// }
+///
+/// fn customer_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Id<String>, D::Error> {
+///     typed_id::serde::nested::deserialize_with_key(deserializer, "customer_id")
+/// }
+///
+/// let order: Order = serde_json::from_str(r#"{"id":{"value":5},"customer":{"customer_id":9}}"#).unwrap();
+/// assert_eq!(order.id, Id::new(5));
+/// assert_eq!(order.customer, Id::new(9));
+/// assert!(serde_json::from_str::<Order>(r#"{"id":5,"customer":9}"#).is_ok());
+/// ```
+pub mod nested {
+    use std::{fmt::Formatter, marker::PhantomData};
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{
+            Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+            value::{
+                BorrowedBytesDeserializer, BorrowedStrDeserializer, BytesDeserializer,
+                SeqAccessDeserializer,
+            },
+        },
+    };
+
+    use crate::Id;
+
+    pub fn serialize<T, I, S>(id: &Id<T, I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Serialize,
+        S: Serializer,
+    {
+        id.id.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, I, D>(deserializer: D) -> Result<Id<T, I>, D::Error>
+    where
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_key(deserializer, "value")
+    }
+
+    /// Like [`deserialize`], accepting objects whose single field is named `key`
+    pub fn deserialize_with_key<'de, T, I, D>(
+        deserializer: D,
+        key: &'static str,
+    ) -> Result<Id<T, I>, D::Error>
+    where
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(NestedVisitor {
+                key,
+                i: PhantomData,
+            })
+            .map(Id::new)
+    }
+
+    struct NestedVisitor<I> {
+        key: &'static str,
+        i: PhantomData<fn() -> I>,
+    }
+
+    /// Forwards a bare value to the backing's `Deserialize`
+    macro_rules! forward_bare {
+        ($($method:ident($t:ty)),* $(,)?) => {
+            $(
+                fn $method<E: Error>(self, v: $t) -> Result<I, E> {
+                    I::deserialize(v.into_deserializer())
+                }
+            )*
+        };
+    }
+
+    impl<'de, I: Deserialize<'de>> Visitor<'de> for NestedVisitor<I> {
+        type Value = I;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "an id or an object with a single {:?} field", self.key)
+        }
+
+        forward_bare! {
+            visit_bool(bool), visit_i64(i64), visit_i128(i128), visit_u64(u64),
+            visit_u128(u128), visit_f64(f64), visit_str(&str), visit_string(String),
+        }
+
+        fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<I, E> {
+            I::deserialize(BorrowedStrDeserializer::new(v))
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<I, E> {
+            I::deserialize(BytesDeserializer::new(v))
+        }
+
+        fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<I, E> {
+            I::deserialize(BorrowedBytesDeserializer::new(v))
+        }
+
+        fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<I, E> {
+            I::deserialize(BytesDeserializer::new(&v))
+        }
+
+        fn visit_unit<E: Error>(self) -> Result<I, E> {
+            I::deserialize(().into_deserializer())
+        }
+
+        fn visit_none<E: Error>(self) -> Result<I, E> {
+            I::deserialize(().into_deserializer())
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<I, D::Error> {
+            I::deserialize(deserializer)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<I, A::Error> {
+            I::deserialize(SeqAccessDeserializer::new(seq))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<I, A::Error> {
+            let key: String = map
+                .next_key()?
+                .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            if key != self.key {
+                return Err(A::Error::unknown_field(&key, &[]));
+            }
+            let value = map.next_value()?;
+            if map.next_key::<String>()?.is_some() {
+                return Err(A::Error::invalid_length(2, &self));
+            }
+            Ok(value)
+        }
+    }
+}
+
+/// Represent an `Option` of a string [`Id`] with `""` standing for `None`
+///
+/// For upstream systems that send an empty string to mean "no id". `null` also deserializes
+/// to `None`.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::Id;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "typed_id::serde::empty_as_none")]
+///     manager: Option<Id<User, String>>,
+/// }
+///
+/// let user: User = serde_json::from_str(r#"{"manager":""}"#).unwrap();
+/// assert_eq!(user.manager, None);
+/// assert_eq!(serde_json::to_string(&user).unwrap(), r#"{"manager":""}"#);
+/// ```
+pub mod empty_as_none {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Id;
+
+    pub fn serialize<T, S>(id: &Option<Id<T, String>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(id.as_ref().map_or("", |id| &id.id))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Id<T, String>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<String>::deserialize(deserializer)?
+            .filter(|id| !id.is_empty())
+            .map(Id::new))
+    }
+}
+
 #[cfg(all(test, feature = "serde"))]
 mod serde_tests {
-    use serde::{Deserialize, Serialize};
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{Visitor, value::Error},
+        forward_to_deserialize_any,
+        ser::{Error as _, Impossible, SerializeSeq, SerializeTuple},
+    };
 
     use crate::Id;
 
@@ -43,9 +470,543 @@ mod serde_tests {
         assert_eq!(user.name, "admin");
     }
 
+    #[test]
+    fn collections_are_flat() {
+        let raw: Vec<u32> = vec![1, 2, u32::MAX];
+        let ids: Vec<Id<User>> = raw.iter().copied().map(Id::new).collect();
+
+        let json = serde_json::to_string(&ids).unwrap();
+        assert_eq!(json, "[1,2,4294967295]");
+        assert_eq!(json, serde_json::to_string(&raw).unwrap());
+        assert_eq!(
+            serde_json::to_value(&ids).unwrap(),
+            serde_json::to_value(&raw).unwrap()
+        );
+        assert_eq!(serde_json::from_str::<Vec<Id<User>>>(&json).unwrap(), ids);
+
+        let array: [Id<User>; 3] = [Id::new(4), Id::new(5), Id::new(6)];
+        let json = serde_json::to_string(&array).unwrap();
+        assert_eq!(json, serde_json::to_string(&[4u32, 5, 6]).unwrap());
+        assert_eq!(serde_json::from_str::<[Id<User>; 3]>(&json).unwrap(), array);
+
+        // JSON can't tell a bare u32 from a newtype around one, so check the serializer calls
+        let mut recorder = Recorder::default();
+        ids.serialize(&mut recorder).unwrap();
+        assert_eq!(
+            recorder.0,
+            [
+                "serialize_seq(Some(3))",
+                "serialize_u32(1)",
+                "serialize_u32(2)",
+                "serialize_u32(4294967295)",
+                "end"
+            ]
+        );
+
+        let mut recorder = Recorder::default();
+        array.serialize(&mut recorder).unwrap();
+        assert_eq!(
+            recorder.0,
+            [
+                "serialize_tuple(3)",
+                "serialize_u32(4)",
+                "serialize_u32(5)",
+                "serialize_u32(6)",
+                "end"
+            ]
+        );
+    }
+
+    #[test]
+    fn newtype_round_trip() {
+        let post = Post {
+            id: 7.into(),
+            author: 1.into(),
+        };
+
+        let json = serde_json::to_string(&post).unwrap();
+        assert_eq!(json, r#"{"id":7,"author":1}"#);
+
+        let result = serde_json::from_str::<Post>(&json).unwrap();
+        assert_eq!(result.id, post.id);
+        assert_eq!(result.author, post.author);
+    }
+
+    #[test]
+    fn newtype_keeps_struct_name() {
+        let mut recorder = Recorder::default();
+        crate::serde::newtype::serialize(&Id::<User>::new(7), &mut recorder).unwrap();
+        assert_eq!(
+            recorder.0,
+            ["serialize_newtype_struct(Id)", "serialize_u32(7)"]
+        );
+
+        let mut names = Vec::new();
+        let deserializer = NewtypeNames {
+            names: &mut names,
+            value: 7,
+        };
+        let id: Id<User> = crate::serde::newtype::deserialize(deserializer).unwrap();
+        assert_eq!(id, Id::new(7));
+        assert_eq!(names, ["Id"]);
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let comment = Comment {
+            id: Id::new(u64::MAX),
+            author: 1.into(),
+        };
+
+        let json = serde_json::to_string(&comment).unwrap();
+        assert_eq!(json, r#"{"id":"18446744073709551615","author":"1"}"#);
+
+        let result = serde_json::from_str::<Comment>(&json).unwrap();
+        assert_eq!(result.id, comment.id);
+        assert_eq!(result.author, comment.author);
+
+        let err = serde_json::from_str::<Comment>(r#"{"id":"x","author":"1"}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid digit"));
+        assert!(serde_json::from_str::<Comment>(r#"{"id":1,"author":"1"}"#).is_err());
+    }
+
+    #[test]
+    fn transparent_wrapper() {
+        let id = UserId(Id::new(9));
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "9");
+        assert_eq!(serde_json::from_str::<UserId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn flattened_keys() {
+        let row = Row {
+            keys: Keys {
+                user: 1.into(),
+                comment: 2.into(),
+            },
+            body: "hi".to_string(),
+        };
+
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"user":1,"comment":"2","body":"hi"}"#);
+
+        let result = serde_json::from_str::<Row>(&json).unwrap();
+        assert_eq!(result.keys.user, row.keys.user);
+        assert_eq!(result.keys.comment, row.keys.comment);
+        assert_eq!(result.body, row.body);
+    }
+
+    #[test]
+    fn flattened_transparent_wrapper_fails() {
+        #[derive(Serialize)]
+        struct Bad {
+            #[serde(flatten)]
+            id: UserId,
+        }
+
+        let err = serde_json::to_string(&Bad {
+            id: UserId(1.into()),
+        })
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("can only flatten structs and maps")
+        );
+    }
+
+    #[test]
+    fn tagged_rejects_other_owners() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Like {
+            #[serde(with = "crate::serde::tagged")]
+            user: Id<User>,
+        }
+
+        #[derive(Serialize)]
+        struct Share {
+            #[serde(with = "crate::serde::tagged")]
+            user: Id<Post>,
+        }
+
+        let json = serde_json::to_string(&Like { user: 3.into() }).unwrap();
+        assert_eq!(
+            json,
+            r#"{"user":{"owner":"typed_id::serde::serde_tests::User","id":3}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Like>(&json).unwrap().user,
+            Id::new(3)
+        );
+
+        let cross_wired = serde_json::to_string(&Share { user: 3.into() }).unwrap();
+        let err = serde_json::from_str::<Like>(&cross_wired).unwrap_err();
+        assert!(err.to_string().contains(
+            "expected id of typed_id::serde::serde_tests::User, found id of typed_id::serde::serde_tests::Post"
+        ));
+    }
+
+    #[test]
+    fn nested_objects() {
+        #[derive(Serialize, Deserialize)]
+        struct Legacy {
+            #[serde(with = "crate::serde::nested")]
+            user: Id<User>,
+            #[serde(with = "crate::serde::nested")]
+            handle: Id<User, String>,
+        }
+
+        let legacy: Legacy =
+            serde_json::from_str(r#"{"user":{"value":5},"handle":{"value":"ann"}}"#).unwrap();
+        assert_eq!(legacy.user, Id::new(5));
+        assert_eq!(legacy.handle.value(), "ann");
+
+        let json = serde_json::to_string(&legacy).unwrap();
+        assert_eq!(json, r#"{"user":5,"handle":"ann"}"#);
+        assert_eq!(
+            serde_json::from_str::<Legacy>(&json).unwrap().user,
+            legacy.user
+        );
+
+        for bad in [
+            r#"{"user":{"id":5},"handle":"ann"}"#,
+            r#"{"user":{"value":5,"extra":1},"handle":"ann"}"#,
+            r#"{"user":{},"handle":"ann"}"#,
+            r#"{"user":-1,"handle":"ann"}"#,
+        ] {
+            assert!(serde_json::from_str::<Legacy>(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn nested_forwards_bare_null_seq_and_bytes() {
+        use serde::de::value::BytesDeserializer;
+
+        #[derive(Serialize, Deserialize)]
+        struct Bare {
+            #[serde(with = "crate::serde::nested")]
+            parent: Id<User, Option<u32>>,
+            #[serde(with = "crate::serde::nested")]
+            digest: Id<User, [u8; 2]>,
+        }
+
+        let bare: Bare = serde_json::from_str(r#"{"parent":null,"digest":[190,239]}"#).unwrap();
+        assert_eq!(bare.parent, Id::new(None));
+        assert_eq!(bare.digest, Id::new([0xbe, 0xef]));
+
+        let bare: Bare =
+            serde_json::from_str(r#"{"parent":{"value":3},"digest":{"value":[1,2]}}"#).unwrap();
+        assert_eq!(bare.parent, Id::new(Some(3)));
+        assert_eq!(bare.digest, Id::new([1, 2]));
+
+        let handle: Id<User, String> =
+            crate::serde::nested::deserialize(BytesDeserializer::<Error>::new(b"ann")).unwrap();
+        assert_eq!(handle.value(), "ann");
+    }
+
+    #[test]
+    fn empty_as_none_round_trips() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Order {
+            #[serde(with = "crate::serde::empty_as_none")]
+            customer: Option<Id<Order, String>>,
+        }
+
+        let none = Order { customer: None };
+        let some = Order {
+            customer: Some(Id::new("cus_1".to_string())),
+        };
+
+        assert_eq!(serde_json::to_string(&none).unwrap(), r#"{"customer":""}"#);
+        assert_eq!(
+            serde_json::to_string(&some).unwrap(),
+            r#"{"customer":"cus_1"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Order>(r#"{"customer":""}"#).unwrap(),
+            none
+        );
+        assert_eq!(
+            serde_json::from_str::<Order>(r#"{"customer":null}"#).unwrap(),
+            none
+        );
+        assert_eq!(
+            serde_json::from_str::<Order>(r#"{"customer":"cus_1"}"#).unwrap(),
+            some
+        );
+    }
+
+    #[test]
+    fn to_json_value_matches_backing() {
+        let number = Id::<User, u64>::new(u64::MAX).to_json_value();
+        assert!(number.is_u64());
+        assert_eq!(number, serde_json::json!(u64::MAX));
+
+        let string = Id::<User, String>::new("usr_1".to_string()).to_json_value();
+        assert_eq!(string.as_str(), Some("usr_1"));
+
+        let negative: serde_json::Value = Id::<User, i32>::new(-3).into();
+        assert!(negative.is_i64());
+        assert_eq!(serde_json::json!({ "id": Id::<User>::new(5) })["id"], 5);
+    }
+
+    #[test]
+    fn composite_id_round_trips_as_string() {
+        struct Post;
+        let key = crate::CompositeId::new((Id::<User>::new(5), Id::<Post, u64>::new(9)));
+
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, r#""5:9""#);
+        assert_eq!(
+            serde_json::from_str::<crate::CompositeId<_>>(&json).unwrap(),
+            key
+        );
+        assert!(
+            serde_json::from_str::<crate::CompositeId<(Id<User>, Id<Post>)>>(r#""59""#).is_err()
+        );
+
+        let with_colon = crate::CompositeId::new((
+            Id::<User, String>::new("a:b".to_string()),
+            Id::<Post, String>::new("c".to_string()),
+        ));
+        let json = serde_json::to_string(&with_colon).unwrap();
+        assert_eq!(json, r#""a%3Ab:c""#);
+        assert_eq!(
+            serde_json::from_str::<crate::CompositeId<_>>(&json).unwrap(),
+            with_colon
+        );
+    }
+
     #[derive(Serialize, Deserialize)]
     struct User {
         id: Id<Self>,
         name: String,
     }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct UserId(Id<User>);
+
+    #[derive(Serialize, Deserialize)]
+    struct Keys {
+        user: Id<User>,
+        #[serde(with = "crate::serde::as_string")]
+        comment: Id<Comment, u64>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Row {
+        #[serde(flatten)]
+        keys: Keys,
+        body: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Comment {
+        #[serde(with = "crate::serde::as_string")]
+        id: Id<Self, u64>,
+        #[serde(with = "crate::serde::as_string")]
+        author: Id<User>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Post {
+        #[serde(with = "crate::serde::newtype")]
+        id: Id<Self>,
+        #[serde(with = "crate::serde::newtype")]
+        author: Id<User>,
+    }
+
+    /// Records each serializer call, since JSON can't show newtype names or wrappers
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    /// Records the primitive serializer methods as `method(value)`
+    macro_rules! record_primitives {
+        ($($method:ident($t:ty)),* $(,)?) => {
+            $(
+                fn $method(self, v: $t) -> Result<(), Error> {
+                    self.0.push(format!("{}({v:?})", stringify!($method)));
+                    Ok(())
+                }
+            )*
+        };
+    }
+
+    impl Serializer for &mut Recorder {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Self;
+        type SerializeTuple = Self;
+        type SerializeTupleStruct = Impossible<(), Error>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeStruct = Impossible<(), Error>;
+        type SerializeStructVariant = Impossible<(), Error>;
+
+        record_primitives! {
+            serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32),
+            serialize_i64(i64), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32),
+            serialize_u64(u64), serialize_f32(f32), serialize_f64(f64), serialize_char(char),
+            serialize_str(&str), serialize_bytes(&[u8]),
+        }
+
+        fn serialize_none(self) -> Result<(), Error> {
+            self.0.push("serialize_none".to_string());
+            Ok(())
+        }
+
+        fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<(), Error> {
+            self.0.push("serialize_some".to_string());
+            value.serialize(self)
+        }
+
+        fn serialize_unit(self) -> Result<(), Error> {
+            self.0.push("serialize_unit".to_string());
+            Ok(())
+        }
+
+        fn serialize_unit_struct(self, name: &'static str) -> Result<(), Error> {
+            self.0.push(format!("serialize_unit_struct({name})"));
+            Ok(())
+        }
+
+        fn serialize_unit_variant(
+            self,
+            name: &'static str,
+            _: u32,
+            variant: &'static str,
+        ) -> Result<(), Error> {
+            self.0
+                .push(format!("serialize_unit_variant({name}::{variant})"));
+            Ok(())
+        }
+
+        fn serialize_newtype_struct<V: Serialize + ?Sized>(
+            self,
+            name: &'static str,
+            value: &V,
+        ) -> Result<(), Error> {
+            self.0.push(format!("serialize_newtype_struct({name})"));
+            value.serialize(self)
+        }
+
+        fn serialize_newtype_variant<V: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &V,
+        ) -> Result<(), Error> {
+            Err(Error::custom("newtype variants aren't recorded"))
+        }
+
+        fn serialize_seq(self, len: Option<usize>) -> Result<Self, Error> {
+            self.0.push(format!("serialize_seq({len:?})"));
+            Ok(self)
+        }
+
+        fn serialize_tuple(self, len: usize) -> Result<Self, Error> {
+            self.0.push(format!("serialize_tuple({len})"));
+            Ok(self)
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            Err(Error::custom("tuple structs aren't recorded"))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error::custom("tuple variants aren't recorded"))
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Err(Error::custom("maps aren't recorded"))
+        }
+
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            Err(Error::custom("structs aren't recorded"))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error::custom("struct variants aren't recorded"))
+        }
+    }
+
+    impl SerializeSeq for &mut Recorder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.0.push("end".to_string());
+            Ok(())
+        }
+    }
+
+    impl SerializeTuple for &mut Recorder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.0.push("end".to_string());
+            Ok(())
+        }
+    }
+
+    /// Deserializes a `u32`, recording the names of newtype structs asked for on the way
+    struct NewtypeNames<'a> {
+        names: &'a mut Vec<&'static str>,
+        value: u32,
+    }
+
+    impl<'de> Deserializer<'de> for NewtypeNames<'_> {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_u32(self.value)
+        }
+
+        fn deserialize_newtype_struct<V: Visitor<'de>>(
+            self,
+            name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.names.push(name);
+            visitor.visit_newtype_struct(self)
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct seq tuple tuple_struct map struct enum
+            identifier ignored_any
+        }
+    }
 }
@@ -0,0 +1,152 @@
+use std::{
+    collections::{HashSet, hash_set},
+    fmt::{Debug, Formatter},
+    hash::Hash,
+};
+
+use crate::{DefaultIdType, Id};
+
+/// `HashSet` of IDs sharing an owner type T
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, IdSet};
+/// let mut admins = IdSet::<String>::new();
+/// admins.insert(Id::new(1));
+/// assert!(admins.contains(&Id::new(1)));
+/// assert!(!admins.contains(&Id::new(2)));
+/// ```
+///
+/// ```compile_fail
+/// use typed_id::{Id, IdSet};
+/// let mut admins = IdSet::<String>::new();
+/// admins.insert(Id::<&str>::new(1)); // wrong owner type
+/// ```
+pub struct IdSet<T, I = DefaultIdType> {
+    ids: HashSet<Id<T, I>>,
+}
+
+impl<T, I> IdSet<T, I> {
+    pub fn new() -> Self {
+        Self {
+            ids: HashSet::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_set::Iter<'_, Id<T, I>> {
+        self.ids.iter()
+    }
+}
+
+impl<T, I: Eq + Hash> IdSet<T, I> {
+    /// Returns whether the ID was newly inserted
+    pub fn insert(&mut self, id: Id<T, I>) -> bool {
+        self.ids.insert(id)
+    }
+
+    pub fn contains(&self, id: &Id<T, I>) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Returns whether the ID was present
+    pub fn remove(&mut self, id: &Id<T, I>) -> bool {
+        self.ids.remove(id)
+    }
+}
+
+impl<T, I: Eq + Hash + Clone> IdSet<T, I> {
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::IdSet;
+    /// let a: IdSet<String> = [1, 2].into_iter().map(Into::into).collect();
+    /// let b: IdSet<String> = [2, 3].into_iter().map(Into::into).collect();
+    /// assert_eq!(a.union(&b).len(), 3);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.ids.union(&other.ids).cloned().collect()
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, IdSet};
+    /// let a: IdSet<String> = [1, 2].into_iter().map(Into::into).collect();
+    /// let b: IdSet<String> = [2, 3].into_iter().map(Into::into).collect();
+    /// let both = a.intersection(&b);
+    /// assert_eq!(both.len(), 1);
+    /// assert!(both.contains(&Id::new(2)));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.ids.intersection(&other.ids).cloned().collect()
+    }
+}
+
+impl<T, I> Default for IdSet<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I: Debug> Debug for IdSet<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.ids.iter()).finish()
+    }
+}
+
+impl<T, I: Clone> Clone for IdSet<T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+        }
+    }
+}
+
+impl<T, I: Eq + Hash> PartialEq for IdSet<T, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl<T, I: Eq + Hash> Eq for IdSet<T, I> {}
+
+impl<T, I: Eq + Hash> FromIterator<Id<T, I>> for IdSet<T, I> {
+    fn from_iter<It: IntoIterator<Item = Id<T, I>>>(iter: It) -> Self {
+        Self {
+            ids: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, I: Eq + Hash> Extend<Id<T, I>> for IdSet<T, I> {
+    fn extend<It: IntoIterator<Item = Id<T, I>>>(&mut self, iter: It) {
+        self.ids.extend(iter);
+    }
+}
+
+impl<T, I> IntoIterator for IdSet<T, I> {
+    type Item = Id<T, I>;
+    type IntoIter = hash_set::IntoIter<Id<T, I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+impl<'a, T, I> IntoIterator for &'a IdSet<T, I> {
+    type Item = &'a Id<T, I>;
+    type IntoIter = hash_set::Iter<'a, Id<T, I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.iter()
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{Clock, Id, SystemClock, TimestampLayout};
+
+type RegressionCallback = Box<dyn FnMut(u64, u64) + Send>;
+
+/// Generates time-ordered `u64` IDs laid out by a [`TimestampLayout`]
+///
+/// Each ID is the current time followed by a sequence number counting IDs generated within
+/// the same millisecond. When a millisecond's sequence numbers run out, or the clock moves
+/// backwards, the generator carries on from its last timestamp plus one instead of waiting,
+/// so IDs stay unique & increasing but may run slightly ahead of the clock.
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{SnowflakeGenerator, TimestampLayout};
+/// let mut ids = SnowflakeGenerator::<String>::new(TimestampLayout::SNOWFLAKE)
+///     .on_clock_regression(|last_ms, now_ms| eprintln!("clock went back {}ms", last_ms - now_ms));
+///
+/// let first = ids.next_id().unwrap();
+/// let second = ids.next_id().unwrap();
+/// assert!(first < second);
+/// ```
+pub struct SnowflakeGenerator<T, C = SystemClock> {
+    layout: TimestampLayout,
+    clock: C,
+    last_ms: Option<u64>,
+    last_reading_ms: Option<u64>,
+    sequence: u64,
+    on_clock_regression: Option<RegressionCallback>,
+    t: PhantomData<fn() -> T>,
+}
+
+impl<T> SnowflakeGenerator<T> {
+    /// # Panics
+    ///
+    /// If the layout's parts add up to more than 64 bits.
+    pub fn new(layout: TimestampLayout) -> Self {
+        Self::with_clock(layout, SystemClock)
+    }
+}
+
+impl<T, C: Clock> SnowflakeGenerator<T, C> {
+    /// Like [`SnowflakeGenerator::new`], reading the time from `clock`
+    pub fn with_clock(layout: TimestampLayout, clock: C) -> Self {
+        assert!(
+            layout.timestamp_bits + layout.sequence_bits <= 64,
+            "SnowflakeGenerator layout must fit in 64 bits"
+        );
+        Self {
+            layout,
+            clock,
+            last_ms: None,
+            last_reading_ms: None,
+            sequence: 0,
+            on_clock_regression: None,
+            t: PhantomData,
+        }
+    }
+
+    /// Calls `callback` with the previous & current clock readings whenever the clock moves
+    /// backwards
+    ///
+    /// Running ahead of the clock after a millisecond's sequence numbers run out isn't a
+    /// regression, as the clock itself hasn't moved back.
+    pub fn on_clock_regression(mut self, callback: impl FnMut(u64, u64) + Send + 'static) -> Self {
+        self.on_clock_regression = Some(Box::new(callback));
+        self
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Returns the next ID, or `None` if the time is before the layout's epoch or past what its
+    /// timestamp bits can hold
+    pub fn next_id(&mut self) -> Option<Id<T, u64>> {
+        let now_ms = self.clock.now_ms();
+        if let Some(last_reading_ms) = self.last_reading_ms.replace(now_ms)
+            && now_ms < last_reading_ms
+            && let Some(callback) = &mut self.on_clock_regression
+        {
+            callback(last_reading_ms, now_ms);
+        }
+        let max_sequence = low_mask(self.layout.sequence_bits);
+
+        // the new state is only stored once the ID is known to fit, so a failed call can't
+        // rewind the sequence & reissue an ID
+        let (ms, sequence) = match self.last_ms {
+            Some(last_ms) if now_ms > last_ms => (now_ms, 0),
+            Some(last_ms) if self.sequence < max_sequence => (last_ms, self.sequence + 1),
+            Some(last_ms) => (last_ms.checked_add(1)?, 0),
+            None => (now_ms, 0),
+        };
+
+        let offset = ms.checked_sub(self.layout.epoch_ms)?;
+        if offset > low_mask(self.layout.timestamp_bits) {
+            return None;
+        }
+        self.last_ms = Some(ms);
+        self.sequence = sequence;
+        let timestamp = offset.checked_shl(self.layout.sequence_bits).unwrap_or(0);
+        Some(Id::new(timestamp | sequence))
+    }
+}
+
+/// The lowest `bits` bits set
+fn low_mask(bits: u32) -> u64 {
+    1u64.checked_shl(bits).map_or(u64::MAX, |bit| bit - 1)
+}
+
+impl<T, C: Debug> Debug for SnowflakeGenerator<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnowflakeGenerator")
+            .field("layout", &self.layout)
+            .field("clock", &self.clock)
+            .field("last_ms", &self.last_ms)
+            .field("last_reading_ms", &self.last_reading_ms)
+            .field("sequence", &self.sequence)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,36 @@
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Marker for backing types that make sensible IDs
+///
+/// Implemented for integers, their `NonZero` counterparts, `String`, `&str` &
+/// [`FixedStr`](crate::FixedStr). It is sealed, so types like `f64` can't opt in. See [`Id::new_strict`](crate::Id::new_strict).
+pub trait StrictBacking: sealed::Sealed {}
+
+macro_rules! strict_backing {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl StrictBacking for $t {}
+        )*
+    };
+}
+
+strict_backing! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    String,
+}
+
+impl sealed::Sealed for &str {}
+impl StrictBacking for &str {}
+
+impl<const N: usize> sealed::Sealed for crate::FixedStr<N> {}
+impl<const N: usize> StrictBacking for crate::FixedStr<N> {}
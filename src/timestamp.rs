@@ -0,0 +1,110 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Clock, Id, UnsignedBacking};
+
+/// Bit layout of integer IDs with a creation timestamp in their high bits
+///
+/// From the least significant bit, an ID is `sequence_bits` of sequence (which may include a
+/// worker or shard number), then `timestamp_bits` of milliseconds since `epoch_ms`. Any bits
+/// above those are ignored.
+///
+/// Examples:
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use typed_id::{Id, TimestampLayout};
+///
+/// // 1 January 2024, 16 bits of sequence
+/// let layout = TimestampLayout::new(1_704_067_200_000, 40, 16);
+/// let id = Id::<String, u64>::new((1_500 << 16) | 3);
+///
+/// assert_eq!(id.timestamp(&layout), UNIX_EPOCH + Duration::from_millis(1_704_067_201_500));
+/// assert_eq!(id.sequence(&layout), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampLayout {
+    pub(crate) epoch_ms: u64,
+    pub(crate) timestamp_bits: u32,
+    pub(crate) sequence_bits: u32,
+}
+
+impl TimestampLayout {
+    /// Twitter's snowflake layout: 41 bits of timestamp & 22 bits of worker & sequence
+    pub const SNOWFLAKE: Self = Self::new(1_288_834_974_657, 41, 22);
+
+    /// # Panics
+    ///
+    /// If either part is wider than 64 bits.
+    pub const fn new(epoch_ms: u64, timestamp_bits: u32, sequence_bits: u32) -> Self {
+        assert!(
+            timestamp_bits <= 64 && sequence_bits <= 64,
+            "TimestampLayout parts must fit in 64 bits"
+        );
+        Self {
+            epoch_ms,
+            timestamp_bits,
+            sequence_bits,
+        }
+    }
+
+    pub fn epoch(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.epoch_ms)
+    }
+
+    fn part(value: u128, shift: u32, bits: u32) -> u64 {
+        let mask = (1u128 << bits) - 1;
+        (value.checked_shr(shift).unwrap_or(0) & mask) as u64
+    }
+}
+
+impl<T, I: UnsignedBacking> Id<T, I> {
+    /// Milliseconds since the Unix epoch at which the ID was created, according to `layout`
+    pub fn timestamp_ms(&self, layout: &TimestampLayout) -> u64 {
+        let offset = TimestampLayout::part(
+            self.id.to_u128(),
+            layout.sequence_bits,
+            layout.timestamp_bits,
+        );
+        layout.epoch_ms.saturating_add(offset)
+    }
+
+    /// Time at which the ID was created, according to `layout`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use typed_id::{Id, TimestampLayout};
+    /// let tweet = Id::<String, u64>::new(1_382_350_606_417_817_604);
+    /// assert_eq!(
+    ///     tweet.timestamp(&TimestampLayout::SNOWFLAKE),
+    ///     UNIX_EPOCH + Duration::from_millis(1_618_413_042_059)
+    /// );
+    /// ```
+    pub fn timestamp(&self, layout: &TimestampLayout) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.timestamp_ms(layout))
+    }
+
+    /// Time since the ID was created according to `layout`, reading the current time from
+    /// `clock`
+    ///
+    /// Zero if the ID's timestamp is in the clock's future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use typed_id::{Id, MockClock, TimestampLayout};
+    /// let layout = TimestampLayout::new(0, 41, 22);
+    /// let id = Id::<String, u64>::new(1_000 << 22);
+    /// assert_eq!(id.age(&layout, &MockClock::new(1_250)), Duration::from_millis(250));
+    /// ```
+    pub fn age(&self, layout: &TimestampLayout, clock: &impl Clock) -> Duration {
+        Duration::from_millis(clock.now_ms().saturating_sub(self.timestamp_ms(layout)))
+    }
+
+    /// The low, non-timestamp bits of the ID, according to `layout`
+    pub fn sequence(&self, layout: &TimestampLayout) -> u64 {
+        TimestampLayout::part(self.id.to_u128(), 0, layout.sequence_bits)
+    }
+}
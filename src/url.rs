@@ -0,0 +1,54 @@
+use std::any::type_name;
+
+use crate::{Id, ParseIdError, ParseIdErrorKind};
+
+impl<T> Id<T, String> {
+    /// Percent-decodes a URL path segment into an ID
+    ///
+    /// `+` is kept as is, since it only means a space in query strings. Malformed escapes &
+    /// escapes decoding to invalid UTF-8 are rejected, as are IDs containing `/`, `\` or control
+    /// characters & the IDs `.` & `..`, so encodings like `%2F` or `%2E%2E` can't escape the
+    /// segment once the ID is used in a path or key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// struct Doc;
+    /// let id = Id::<Doc, String>::from_url_segment("a%20b").unwrap();
+    /// assert_eq!(id, Id::new("a b".to_string()));
+    /// assert!(Id::<Doc, String>::from_url_segment("a%2").is_err());
+    /// assert!(Id::<Doc, String>::from_url_segment("..%2F..%2Fetc").is_err());
+    /// assert!(Id::<Doc, String>::from_url_segment("%2E%2E").is_err());
+    /// assert!(Id::<Doc, String>::from_url_segment("..%5Cetc").is_err());
+    /// ```
+    pub fn from_url_segment(segment: &str) -> Result<Self, ParseIdError> {
+        let error = |kind| ParseIdError::new(segment, type_name::<T>(), kind);
+        let mut bytes = Vec::with_capacity(segment.len());
+        let mut rest = segment.as_bytes();
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte != b'%' {
+                bytes.push(byte);
+                rest = tail;
+                continue;
+            }
+
+            let hex_digit = |byte: u8| char::from(byte).to_digit(16);
+            let escape = match tail {
+                [hi, lo, ..] => hex_digit(*hi).zip(hex_digit(*lo)),
+                _ => None,
+            }
+            .map(|(hi, lo)| (hi * 16 + lo) as u8)
+            .ok_or_else(|| error(ParseIdErrorKind::Malformed))?;
+            bytes.push(escape);
+            rest = &tail[2..];
+        }
+
+        String::from_utf8(bytes)
+            .ok()
+            .filter(|id| id != "." && id != "..")
+            .filter(|id| !id.chars().any(|c| c == '/' || c == '\\' || c.is_control()))
+            .map(Id::new)
+            .ok_or_else(|| error(ParseIdErrorKind::Malformed))
+    }
+}
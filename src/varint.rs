@@ -0,0 +1,70 @@
+use crate::{Id, UnsignedBacking, VarintError};
+
+impl<T, I: UnsignedBacking> Id<T, I> {
+    /// Encodes the backing as an unsigned LEB128 varint, 7 bits per byte
+    ///
+    /// Small IDs take a single byte, so persisting many of them is compact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::Id;
+    /// assert_eq!(Id::<String, u64>::new(5).to_varint(), [0x05]);
+    /// assert_eq!(Id::<String, u64>::new(300).to_varint(), [0xac, 0x02]);
+    /// ```
+    pub fn to_varint(&self) -> Vec<u8> {
+        let mut value = self.id.to_u128();
+        let mut out = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    /// Decodes a varint written by [`Id::to_varint`] from the start of `bytes`
+    ///
+    /// Returns the ID & the number of bytes consumed, so several IDs can be read back to back.
+    /// Overlong encodings, padded with `0x80` bytes before a final `0x00`, are rejected so each ID
+    /// has exactly one byte form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_id::{Id, VarintError};
+    /// let bytes = [0xac, 0x02, 0x05];
+    /// assert_eq!(Id::<String, u64>::from_varint(&bytes), Ok((Id::new(300), 2)));
+    /// assert_eq!(Id::<String, u64>::from_varint(&bytes[2..]), Ok((Id::new(5), 1)));
+    /// assert_eq!(Id::<String, u64>::from_varint(&[0x80]), Err(VarintError::Truncated));
+    /// assert_eq!(Id::<String, u8>::from_varint(&[0xac, 0x02]), Err(VarintError::Overflow));
+    /// assert_eq!(Id::<String, u64>::from_varint(&[0x85, 0x00]), Err(VarintError::Overlong));
+    /// ```
+    pub fn from_varint(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+        let max_len = I::BITS.div_ceil(7) as usize;
+        let mut value: u128 = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            if i >= max_len {
+                return Err(VarintError::Overflow);
+            }
+            let shift = i as u32 * 7;
+            let bits = u128::from(byte & 0x7f);
+            if (bits << shift) >> shift != bits {
+                return Err(VarintError::Overflow);
+            }
+            value |= bits << shift;
+
+            if byte & 0x80 == 0 {
+                if i > 0 && byte == 0 {
+                    return Err(VarintError::Overlong);
+                }
+                let id = I::from_u128(value).ok_or(VarintError::Overflow)?;
+                return Ok((Id::new(id), i + 1));
+            }
+        }
+        Err(VarintError::Truncated)
+    }
+}
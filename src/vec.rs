@@ -0,0 +1,109 @@
+use std::{
+    fmt::{Debug, Formatter},
+    ops::{Deref, DerefMut},
+    slice, vec,
+};
+
+use crate::{DefaultIdType, Id};
+
+/// `Vec` of IDs sharing an owner type T, collectable from backing values
+///
+/// Examples:
+///
+/// ```
+/// use typed_id::{Id, IdVec};
+/// let ids: IdVec<String> = (0..3).collect();
+/// assert_eq!(ids.len(), 3);
+/// assert_eq!(ids[1], Id::new(1));
+/// ```
+pub struct IdVec<T, I = DefaultIdType> {
+    ids: Vec<Id<T, I>>,
+}
+
+impl<T, I> IdVec<T, I> {
+    pub fn new() -> Self {
+        Self { ids: Vec::new() }
+    }
+
+    pub fn push(&mut self, id: Id<T, I>) {
+        self.ids.push(id);
+    }
+
+    pub fn into_vec(self) -> Vec<Id<T, I>> {
+        self.ids
+    }
+}
+
+impl<T, I> Deref for IdVec<T, I> {
+    type Target = [Id<T, I>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.ids
+    }
+}
+
+impl<T, I> DerefMut for IdVec<T, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ids
+    }
+}
+
+impl<T, I> FromIterator<I> for IdVec<T, I> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        Self {
+            ids: iter.into_iter().map(Id::new).collect(),
+        }
+    }
+}
+
+impl<T, I> From<Vec<Id<T, I>>> for IdVec<T, I> {
+    fn from(ids: Vec<Id<T, I>>) -> Self {
+        Self { ids }
+    }
+}
+
+impl<T, I> Default for IdVec<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I: Debug> Debug for IdVec<T, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.ids.iter()).finish()
+    }
+}
+
+impl<T, I: Clone> Clone for IdVec<T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+        }
+    }
+}
+
+impl<T, I: PartialEq> PartialEq for IdVec<T, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl<T, I: Eq> Eq for IdVec<T, I> {}
+
+impl<T, I> IntoIterator for IdVec<T, I> {
+    type Item = Id<T, I>;
+    type IntoIter = vec::IntoIter<Id<T, I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+impl<'a, T, I> IntoIterator for &'a IdVec<T, I> {
+    type Item = &'a Id<T, I>;
+    type IntoIter = slice::Iter<'a, Id<T, I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.iter()
+    }
+}
@@ -0,0 +1,49 @@
+use std::num::{Saturating, Wrapping};
+
+use crate::Id;
+
+// `Id` has no arithmetic operators of its own, so arithmetic happens on the backing taken out
+// with `into_inner` or `value`. Backing an ID with `Wrapping` or `Saturating` picks the
+// overflow behaviour of that arithmetic, & these conversions switch between the two.
+
+/// # Examples
+///
+/// ```
+/// use std::num::Wrapping;
+/// use typed_id::Id;
+/// let id = Id::<String, Wrapping<u8>>::from(Id::<String, u8>::new(255));
+/// let next = Id::<String, Wrapping<u8>>::new(id.into_inner() + Wrapping(1));
+/// assert_eq!(Id::<String, u8>::from(next), Id::new(0));
+/// ```
+impl<T, I> From<Id<T, I>> for Id<T, Wrapping<I>> {
+    fn from(id: Id<T, I>) -> Self {
+        Id::new(Wrapping(id.id))
+    }
+}
+
+impl<T, I> From<Id<T, Wrapping<I>>> for Id<T, I> {
+    fn from(id: Id<T, Wrapping<I>>) -> Self {
+        Id::new(id.id.0)
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// use std::num::Saturating;
+/// use typed_id::Id;
+/// let id = Id::<String, Saturating<u8>>::from(Id::<String, u8>::new(255));
+/// let next = Id::<String, Saturating<u8>>::new(id.into_inner() + Saturating(1));
+/// assert_eq!(Id::<String, u8>::from(next), Id::new(255));
+/// ```
+impl<T, I> From<Id<T, I>> for Id<T, Saturating<I>> {
+    fn from(id: Id<T, I>) -> Self {
+        Id::new(Saturating(id.id))
+    }
+}
+
+impl<T, I> From<Id<T, Saturating<I>>> for Id<T, I> {
+    fn from(id: Id<T, Saturating<I>>) -> Self {
+        Id::new(id.id.0)
+    }
+}
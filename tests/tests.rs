@@ -13,6 +13,21 @@ mod tests {
         assert!(id >= Id::new(4));
     }
 
+    #[test]
+    fn comparisons_with_backing() {
+        use std::cmp::Ordering;
+
+        let id = Id::<User>::new(5);
+        assert!(id.eq_value(&5));
+        assert!(!id.eq_value(&6));
+        assert_eq!(id.partial_cmp_value(&6), Some(Ordering::Less));
+        assert_eq!(id.partial_cmp_value(&4), Some(Ordering::Greater));
+        assert_eq!(id.partial_cmp_value(&5), Some(Ordering::Equal));
+
+        // Id == Id comparisons still infer their right-hand side
+        assert!(id == 5.into());
+    }
+
     #[test]
     fn format_strings() {
         let id = Id::<User>::new(5);
@@ -34,6 +49,62 @@ mod tests {
         assert_eq!(id.backing_type(), "i64");
     }
 
+    #[test]
+    fn format_flags() {
+        let pos = Id::<User, i64>::new(25);
+        let neg = Id::<User, i64>::new(-25);
+        assert_eq!(format!("{:+}", pos), "+25");
+        assert_eq!(format!("{:+}", neg), "-25");
+        assert_eq!(format!("{:+05}", pos), "+0025");
+        assert_eq!(format!("{:>4}", pos), "  25");
+        assert_eq!(format!("{:<4}|", neg), "-25 |");
+    }
+
+    #[test]
+    fn type_ids() {
+        let user = Id::<User>::new(1);
+        let string = Id::<String>::new(1);
+
+        assert_ne!(user.owner_type_id(), string.owner_type_id());
+        assert_eq!(
+            user.owner_type_id(),
+            Id::<User, i64>::new(1).owner_type_id()
+        );
+        assert_eq!(user.backing_type_id(), string.backing_type_id());
+        assert_ne!(
+            user.backing_type_id(),
+            Id::<User, i64>::new(1).backing_type_id()
+        );
+    }
+
+    #[test]
+    fn truncated_shortens_string_ids() {
+        let id = Id::<User, String>::new("3f2a1b9c-8d7e-4f60-a5b4-c3d2e1f0a9b8".to_string());
+        assert_eq!(id.truncated(8).to_string(), "3f2a1b9c…");
+        assert_eq!(id.truncated(0).to_string(), "…");
+        assert_eq!(format!("{:>12}", id.truncated(8)), "   3f2a1b9c…");
+        assert_eq!(format!("{:-^11}", id.truncated(8)), "-3f2a1b9c…-");
+
+        let short = Id::<User, &str>::new("abc");
+        assert_eq!(short.truncated(8).to_string(), "abc");
+        assert_eq!(format!("{:<5}|", short.truncated(8)), "abc  |");
+
+        let fixed = Id::<User, FixedStr<12>>::new_fixed("usr_12345678").unwrap();
+        assert_eq!(fixed.truncated(4).to_string(), "usr_…");
+    }
+
+    #[test]
+    fn format_precision_forwards_to_backing() {
+        let id = Id::<User, String>::new("3f2a1b9c-8d7e".to_string());
+        assert_eq!(format!("{:.8}", id), "3f2a1b9c");
+        assert_eq!(
+            format!("{:.4}", Id::<User, u64>::new(1234567890)),
+            "1234567890"
+        );
+        assert_eq!(format!("{:+.2}", Id::<User, f64>::new(1.5)), "+1.50");
+        assert_eq!(format!("{:05.1}", Id::<User, i32>::new(-7)), "-0007");
+    }
+
     #[test]
     fn non_default_int_id_type() {
         let id = Id::<String, i64>::new(-10);
@@ -115,15 +186,1563 @@ mod tests {
         assert_eq!(id3.backing_type(), "&str");
     }
 
-    #[derive(Debug, PartialEq)]
-    struct User {
-        id: Id<Self>,
-        name: String,
+    #[test]
+    fn parse_from_str() {
+        let id: Id<User> = "42".parse().unwrap();
+        assert_eq!(id, Id::new(42));
+
+        let id: Id<User, i64> = "-7".parse().unwrap();
+        assert_eq!(id, Id::new(-7));
+
+        let err = "nope".parse::<Id<User>>().unwrap_err();
+        assert_eq!(err.input(), "nope");
+        assert_eq!(err.owner_type(), "tests::tests::User");
+        assert!(matches!(err.kind(), ParseIdErrorKind::Backing(_)));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid tests::tests::User id "nope": invalid digit found in string"#
+        );
     }
 
-    impl HasId for User {
-        fn id(&self) -> Id<Self> {
-            self.id
+    #[test]
+    fn id_set_union() {
+        let mut a = IdSet::<User>::new();
+        a.insert(Id::new(1));
+        a.insert(Id::new(2));
+        let b: IdSet<User> = [2, 3].into_iter().map(Id::new).collect();
+
+        let all = a.union(&b);
+        let mut ids: Vec<_> = all.iter().map(Id::value).collect();
+        ids.sort();
+        assert_eq!(ids, [1, 2, 3]);
+
+        let both = a.intersection(&b);
+        assert_eq!(both.len(), 1);
+        assert!(both.contains(&Id::new(2)));
+
+        assert!(a.remove(&Id::new(1)));
+        assert!(!a.remove(&Id::new(1)));
+        assert_eq!(a.len(), 1);
+    }
+
+    #[test]
+    fn namespaced_id() {
+        let id = NamespacedId::new("tenant-a", Id::<User>::new(7));
+        assert_eq!(id.namespace(), "tenant-a");
+        assert_eq!(id.id(), &Id::new(7));
+        assert_eq!(id.to_string(), "tenant-a:7");
+
+        let parsed: NamespacedId<User> = id.to_string().parse().unwrap();
+        assert_eq!(parsed, id);
+
+        let with_colon: NamespacedId<User, String> = "t:a:b".parse().unwrap();
+        assert_eq!(with_colon.namespace(), "t");
+        assert_eq!(with_colon.id().value(), "a:b");
+
+        // a ':' in the namespace is escaped, so tenants "a:b" & "a" can't collide
+        let colon_namespace = NamespacedId::new("a:b", Id::<User, String>::new("c".to_string()));
+        assert_eq!(colon_namespace.to_string(), "a%3Ab:c");
+        assert_eq!(colon_namespace.to_string().parse(), Ok(colon_namespace));
+        let percent_namespace = NamespacedId::new("100%", Id::<User>::new(1));
+        assert_eq!(percent_namespace.to_string(), "100%25:1");
+        assert_eq!(percent_namespace.to_string().parse(), Ok(percent_namespace));
+        let err = "a%3ab:c".parse::<NamespacedId<User, String>>().unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Malformed);
+
+        let err = "tenant-a".parse::<NamespacedId<User>>().unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::MissingSeparator(':'));
+
+        let err = "tenant-a:x".parse::<NamespacedId<User>>().unwrap_err();
+        assert_eq!(err.input(), "tenant-a:x");
+        assert!(matches!(err.kind(), ParseIdErrorKind::Backing(_)));
+    }
+
+    #[test]
+    fn id_vec_from_range() {
+        let ids: IdVec<User> = (0..5).collect();
+        assert_eq!(ids.len(), 5);
+        assert_eq!(ids.first(), Some(&Id::new(0)));
+        assert_eq!(ids.last(), Some(&Id::new(4)));
+        assert!(ids.iter().zip(0..).all(|(id, i)| id.value() == i));
+
+        let empty: IdVec<User, i64> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn backing_from_owner() {
+        type PostId = IdOf<Post>;
+
+        let id = PostId::new(u64::MAX);
+        assert_eq!(id.value(), u64::MAX);
+        assert_eq!(id.backing_type(), "u64");
+        assert_eq!(id, Id::<Post, u64>::new(u64::MAX));
+    }
+
+    #[test]
+    fn public_ids() {
+        let id = Id::<User, u64>::new(u64::MAX);
+        let encoded = id.public_id();
+        assert_eq!(encoded, "usr_LygHa16AHYF");
+        assert_eq!(Id::from_public_id(&encoded), Ok(id));
+
+        assert_eq!(Id::<User>::new(0).public_id(), "usr_0");
+        assert_eq!(Id::<User>::from_public_id("usr_0"), Ok(Id::new(0)));
+
+        let err = Id::<User>::from_public_id("pst_JU").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ParseIdErrorKind::PrefixMismatch {
+                expected: "usr",
+                found: "pst".to_string()
+            }
+        );
+        assert_eq!(err.input(), "pst_JU");
+
+        let err = Id::<User>::from_public_id("JU").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::MissingSeparator('_'));
+        let err = Id::<User>::from_public_id("usr_").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Empty);
+        let err = Id::<User>::from_public_id("usr_J!").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('!'));
+        let err = Id::<User, u8>::from_public_id("usr_zz").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Overflow);
+    }
+
+    #[test]
+    fn public_ids_have_one_encoding() {
+        assert_eq!(Id::<User>::from_public_id("usr_Ju"), Ok(Id::new(1234)));
+        for padded in ["usr_0Ju", "usr_00Ju", "usr_00"] {
+            let err = Id::<User>::from_public_id(padded).unwrap_err();
+            assert_eq!(err.kind(), &ParseIdErrorKind::Malformed, "{padded}");
+        }
+        assert!(Id::<User>::from_base62("0Ju").is_err());
+        assert!("0Ju".parse::<FormattedId<User, Base62>>().is_err());
+    }
+
+    #[cfg(feature = "nanoid")]
+    #[test]
+    fn nanoid() {
+        let a = Id::<User, String>::new_nanoid();
+        let b = Id::<User, String>::new_nanoid();
+        assert_ne!(a, b);
+        assert_eq!(a.value().len(), 21);
+        assert!(
+            a.value()
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        );
+
+        assert_eq!(Id::<User, String>::new_nanoid_of_len(40).value().len(), 40);
+        assert_eq!(Id::<User, String>::new_nanoid_of_len(0).value(), "");
+        assert_ne!(Id::<User, String>::try_new_nanoid().unwrap(), a);
+    }
+
+    #[test]
+    fn usize_indexing() {
+        let users = ["alice", "bob", "eve"];
+        let ids: Vec<Id<User, u16>> = (0..users.len()).map(Id::from_usize).collect();
+
+        for (id, name) in ids.iter().zip(users) {
+            assert_eq!(users[id.as_usize()], name);
+            assert_eq!(*id, Id::from_usize(id.as_usize()));
+        }
+
+        let out_of_bounds = Id::<User, u16>::new(3);
+        assert!(users.get(out_of_bounds.as_usize()).is_none());
+
+        assert_eq!(Id::<User, i8>::new(-1).checked_as_usize(), None);
+        assert_eq!(Id::<User, u8>::checked_from_usize(300), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in usize")]
+    fn negative_as_usize() {
+        Id::<User, i64>::new(-1).as_usize();
+    }
+
+    #[test]
+    fn inclusive_ranges() {
+        let ids: Vec<Id<User>> = Id::range_inclusive(Id::new(3), Id::new(6)).collect();
+        assert_eq!(ids, [3, 4, 5, 6].map(Id::new));
+
+        let single: Vec<Id<User, i8>> = Id::range_inclusive(Id::new(-1), Id::new(-1)).collect();
+        assert_eq!(single, [Id::new(-1)]);
+
+        let reversed = Id::<User>::range_inclusive(Id::new(6), Id::new(3));
+        assert_eq!(reversed.count(), 0);
+
+        let to_max = Id::<User, u8>::range_inclusive(Id::new(250), Id::new(u8::MAX));
+        assert_eq!(to_max.count(), 6);
+    }
+
+    #[test]
+    fn erased_ids_ordering() {
+        let mut registry = std::collections::BTreeMap::<Box<dyn ErasedId>, &str>::new();
+        registry.insert(Box::new(Id::<User>::new(2)), "user 2");
+        registry.insert(Box::new(Id::<Post, u64>::new(7)), "post 7");
+        registry.insert(Box::new(Id::<User>::new(1)), "user 1");
+        registry.insert(Box::new(Id::<User, i64>::new(0)), "user 0 (i64)");
+        registry.insert(Box::new(Id::<Post, u64>::new(3)), "post 3");
+
+        let order: Vec<_> = registry.values().copied().collect();
+        assert_eq!(
+            order,
+            ["post 3", "post 7", "user 0 (i64)", "user 1", "user 2"]
+        );
+
+        let key: Box<dyn ErasedId> = Box::new(Id::<User>::new(1));
+        assert_eq!(registry.get(&key), Some(&"user 1"));
+        let other_owner: Box<dyn ErasedId> = Box::new(Id::<Post>::new(1));
+        assert_eq!(registry.get(&other_owner), None);
+    }
+
+    #[test]
+    fn bounded_construction() {
+        let valid = 1..=10_000;
+        assert_eq!(Id::<User>::new_in_range(1, valid.clone()), Some(Id::new(1)));
+        assert_eq!(
+            Id::<User>::new_in_range(10_000, valid.clone()),
+            Some(Id::new(10_000))
+        );
+        assert_eq!(Id::<User>::new_in_range(0, valid.clone()), None);
+        assert_eq!(Id::<User>::new_in_range(10_001, valid), None);
+
+        assert_eq!(
+            Id::<User, i32>::new_in_range(-5, -10..=-1),
+            Some(Id::new(-5))
+        );
+        assert_eq!(Id::<User, i32>::new_in_range(0, -10..=-1), None);
+    }
+
+    #[test]
+    fn metric_values() {
+        assert_eq!(Id::<User, u8>::new(u8::MAX).as_metric_value(), 255);
+        assert_eq!(Id::<User, i16>::new(-300).as_metric_value(), -300);
+        assert_eq!(Id::<User>::new(u32::MAX).as_metric_value(), 4_294_967_295);
+        assert_eq!(Id::<User, i64>::new(i64::MIN).as_metric_value(), i64::MIN);
+        assert_eq!(Id::<User, u64>::new(u64::MAX).as_metric_value(), i64::MAX);
+        assert_eq!(Id::<User, i128>::new(i128::MIN).as_metric_value(), i64::MIN);
+        assert_eq!(Id::<User, u128>::new(u128::MAX).as_metric_value(), i64::MAX);
+    }
+
+    #[test]
+    fn bridged_newtype() {
+        struct LegacyUserId(u32);
+        bridge_id!(LegacyUserId => User, u32);
+
+        let id: Id<User> = LegacyUserId(12).into();
+        assert_eq!(id, Id::new(12));
+
+        let legacy = LegacyUserId::from(id);
+        assert_eq!(legacy.0, id.value());
+    }
+
+    #[cfg(feature = "hashids")]
+    #[test]
+    fn hashids() {
+        const SALT: &str = "user salt";
+
+        for value in [0, 1, 99, 100, 12345, u64::MAX] {
+            let id = Id::<User, u64>::new(value);
+            let hashid = id.to_hashid(SALT);
+            assert_ne!(hashid, value.to_string());
+            assert_eq!(Id::from_hashid(&hashid, SALT), Ok(id));
+        }
+
+        let id = Id::<User>::new(42);
+        assert_ne!(id.to_hashid("salt a"), id.to_hashid("salt b"));
+        assert_ne!(id.to_hashid(SALT), Id::<User>::new(43).to_hashid(SALT));
+
+        let err = Id::<User>::from_hashid("", SALT).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Empty);
+        let err = Id::<User>::from_hashid("a!", SALT).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('!'));
+        let too_big = Id::<User, u64>::new(u64::MAX).to_hashid(SALT);
+        let err = Id::<User>::from_hashid(&too_big, SALT).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Overflow);
+    }
+
+    #[test]
+    fn striding_generators() {
+        let shard_0: Vec<_> = IdGenerator::<User, u64>::with_stride(0, 2)
+            .take(100)
+            .collect();
+        let shard_1: Vec<_> = IdGenerator::<User, u64>::with_stride(1, 2)
+            .take(100)
+            .collect();
+
+        assert_eq!(shard_0[..3], [0, 2, 4].map(Id::new));
+        assert_eq!(shard_1[..3], [1, 3, 5].map(Id::new));
+        assert!(shard_0.windows(2).all(|w| w[1].value() - w[0].value() == 2));
+        assert!(shard_0.iter().all(|id| !shard_1.contains(id)));
+
+        let mut near_max = IdGenerator::<User, u8>::with_stride(250, 5);
+        assert_eq!(near_max.next_id(), Some(Id::new(250)));
+        assert_eq!(near_max.next_id(), Some(Id::new(255)));
+        assert_eq!(near_max.next_id(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "stride must be positive")]
+    fn zero_stride() {
+        IdGenerator::<User>::with_stride(0, 0);
+    }
+
+    #[test]
+    fn short_codes() {
+        let id = Id::<User>::new(42);
+        assert_eq!(id.short_code(8), Id::<User>::new(42).short_code(8));
+        assert_eq!(id.short_code(30).len(), 30);
+        assert_eq!(id.short_code(0), "");
+        // pinned, as codes must be the same on every platform
+        assert_eq!(id.short_code(14), "QNGVHJZP0EFDRK");
+        assert!(
+            id.short_code(30)
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        );
+
+        let codes: std::collections::HashSet<_> = (0..1000)
+            .map(|i| Id::<User>::new(i).short_code(8))
+            .collect();
+        assert!(codes.len() > 990);
+    }
+
+    #[test]
+    fn auto_traits_follow_backing() {
+        fn assert_send<S: Send>() {}
+        fn assert_sync<S: Sync>() {}
+        fn assert_unpin<S: Unpin>() {}
+
+        // neither Send, Sync nor Unpin
+        type Owner = (std::rc::Rc<()>, std::marker::PhantomPinned);
+
+        assert_send::<Id<Owner>>();
+        assert_sync::<Id<Owner>>();
+        assert_unpin::<Id<Owner>>();
+        assert_send::<Id<Owner, String>>();
+        assert_sync::<Id<Owner, String>>();
+        assert_unpin::<Id<Owner, String>>();
+
+        // Cell is Send but not Sync
+        assert_send::<Id<User, std::cell::Cell<u32>>>();
+    }
+
+    #[test]
+    fn dyn_id_downcasts() {
+        let ids = [
+            DynId::from(Id::<User>::new(1)),
+            DynId::from(Id::<Post>::new(1)),
+            DynId::from(Id::<User>::new(2)),
+        ];
+
+        assert_ne!(ids[0], ids[1]);
+        assert_eq!(ids[0].owner_type(), "tests::tests::User");
+        assert_eq!(
+            format!("{:?}", ids[1]),
+            "DynId<owner: tests::tests::Post, backing: u32>(1)"
+        );
+
+        let users: Vec<Id<User>> = ids
+            .iter()
+            .filter_map(|id| id.downcast::<User>().ok())
+            .collect();
+        assert_eq!(users, [Id::new(1), Id::new(2)]);
+
+        let not_a_post = ids[0].downcast::<Post>().unwrap_err();
+        assert_eq!(not_a_post, ids[0]);
+        assert_eq!(ids[1].downcast::<Post>(), Ok(Id::new(1)));
+    }
+
+    #[test]
+    fn from_env() {
+        use std::{collections::HashMap, env::VarError, ffi::OsString};
+
+        let vars = HashMap::from([("USER_ID", "42"), ("BAD_ID", "abc")]);
+        let lookup = |var: &str| {
+            vars.get(var)
+                .map(|v| v.to_string())
+                .ok_or(VarError::NotPresent)
+        };
+
+        assert_eq!(
+            Id::<User>::from_env_with("USER_ID", lookup),
+            Ok(Id::new(42))
+        );
+        assert!(matches!(
+            Id::<User>::from_env_with("BAD_ID", lookup),
+            Err(FromEnvError::Invalid { var, .. }) if var == "BAD_ID"
+        ));
+        assert_eq!(
+            Id::<User>::from_env_with("ODD_ID", |_| Err(VarError::NotUnicode(OsString::new()))),
+            Err(FromEnvError::NotUnicode("ODD_ID".to_string()))
+        );
+        assert_eq!(
+            Id::<User>::from_env("TYPED_ID_TEST_UNSET"),
+            Err(FromEnvError::Missing("TYPED_ID_TEST_UNSET".to_string()))
+        );
+    }
+
+    #[test]
+    fn fixed_length_codes() {
+        type InviteCode = Id<User, FixedStr<6>>;
+
+        let code = InviteCode::new_fixed("AB12CD").unwrap();
+        assert_eq!(code.value().as_str(), "AB12CD");
+        // length is counted in characters, not bytes
+        assert!(InviteCode::new_fixed("ÄB12CD").is_some());
+
+        assert_eq!(InviteCode::new_fixed("AB12C"), None);
+        assert_eq!(InviteCode::new_fixed("AB12CDE"), None);
+        assert_eq!(InviteCode::new_fixed(""), None);
+    }
+
+    #[test]
+    fn sortable_bytes() {
+        let mut signed: Vec<Id<User, i64>> = [5, -1, i64::MIN, 0, 300, -300, i64::MAX, 1]
+            .into_iter()
+            .map(Id::new)
+            .collect();
+        let mut by_bytes = signed.clone();
+        signed.sort();
+        by_bytes.sort_by_key(|id| id.to_sortable_bytes());
+        assert_eq!(by_bytes, signed);
+
+        let mut unsigned: Vec<Id<Post, u64>> = [256, 1, u64::MAX, 0, 255]
+            .into_iter()
+            .map(Id::new)
+            .collect();
+        let mut by_bytes = unsigned.clone();
+        unsigned.sort();
+        by_bytes.sort_by_key(|id| id.to_sortable_bytes());
+        assert_eq!(by_bytes, unsigned);
+    }
+
+    #[test]
+    fn quoted_display() {
+        let id = Id::<User>::new(5);
+        assert_eq!(id.quoted().to_string(), "\"5\"");
+        assert_eq!(Id::<Post, i64>::new(-3).quoted().to_string(), "\"-3\"");
+    }
+
+    #[test]
+    fn nonzero_primitive_conversions() {
+        use std::num::{NonZeroI64, NonZeroU32};
+
+        let id = Id::<User, NonZeroU32>::new(NonZeroU32::new(7).unwrap());
+        let primitive: Id<User, u32> = id.to_primitive();
+        assert_eq!(primitive, Id::new(7));
+        assert_eq!(
+            Id::<User, NonZeroU32>::try_from_primitive(primitive),
+            Some(id)
+        );
+
+        assert_eq!(Id::<User, NonZeroU32>::try_from_primitive(Id::new(0)), None);
+        assert_eq!(Id::<Post, NonZeroI64>::try_from_primitive(Id::new(0)), None);
+    }
+
+    #[test]
+    fn id_enums() {
+        id_enum! {
+            /// ID of anything a command can target
+            AnyId { User(u32), Post(u64) }
+        }
+
+        fn describe(id: &AnyId) -> String {
+            match id {
+                AnyId::User(id) => format!("user #{id}"),
+                AnyId::Post(id) => format!("post #{id}"),
+            }
+        }
+
+        let ids: Vec<AnyId> = vec![Id::<User>::new(1).into(), Id::<Post, u64>::new(1).into()];
+        assert_ne!(ids[0], ids[1]);
+        assert_eq!(ids[0].to_string(), "User(1)");
+        assert_eq!(describe(&ids[1]), "post #1");
+        assert_eq!(ids[1].clone(), AnyId::from(Id::<Post, u64>::new(1)));
+    }
+
+    #[test]
+    fn timestamped_ids() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        const EPOCH_MS: u64 = 1_600_000_000_000;
+        let layout = TimestampLayout::new(EPOCH_MS, 41, 12);
+
+        let offset_ms: u64 = 86_400_000;
+        let packed = (offset_ms << 12) | 0xabc;
+        // bits above the layout are ignored
+        let id = Id::<User, u64>::new(packed | (1 << 63));
+
+        assert_eq!(id.timestamp_ms(&layout), EPOCH_MS + offset_ms);
+        assert_eq!(
+            id.timestamp(&layout),
+            layout.epoch() + Duration::from_millis(offset_ms)
+        );
+        assert_eq!(id.sequence(&layout), 0xabc);
+
+        let at_epoch = Id::<Post, u64>::new(7);
+        assert_eq!(
+            at_epoch.timestamp(&layout),
+            UNIX_EPOCH + Duration::from_millis(EPOCH_MS)
+        );
+    }
+
+    #[test]
+    fn backing_widths() {
+        assert_backing_fits!(u8 => u8);
+        assert_backing_fits!(u16 => i32);
+        assert_backing_fits!(i32 => i128);
+
+        assert!(Id::<User, u64>::fits_in::<u128>());
+        assert!(Id::<User, i16>::fits_in::<i16>());
+        assert!(!Id::<User, u64>::fits_in::<u32>());
+        assert!(!Id::<User, u64>::fits_in::<i64>());
+        assert!(!Id::<User, i8>::fits_in::<u128>());
+    }
+
+    #[test]
+    fn luhn_checked_strings() {
+        for value in [0, 7, 18, 1234, u32::MAX] {
+            let id = Id::<User>::new(value);
+            let checked = id.to_string_checked();
+            assert_eq!(checked.len(), value.to_string().len() + 1);
+            assert_eq!(Id::from_str_checked(&checked), Ok(id));
+
+            // changing any single digit must be caught
+            for i in 0..checked.len() {
+                let original = checked.as_bytes()[i];
+                for digit in (b'0'..=b'9').filter(|d| *d != original) {
+                    let mut typo = checked.clone().into_bytes();
+                    typo[i] = digit;
+                    let typo = String::from_utf8(typo).unwrap();
+                    let err = Id::<User>::from_str_checked(&typo).unwrap_err();
+                    assert_eq!(err.kind(), &ParseIdErrorKind::ChecksumMismatch, "{typo}");
+                }
+            }
+        }
+
+        let err = Id::<User>::from_str_checked("12a4").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('a'));
+        let err = Id::<User>::from_str_checked("0").unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Malformed);
+    }
+
+    #[test]
+    fn luhn_checked_strings_have_one_form() {
+        assert_eq!(
+            Id::<User, u64>::from_str_checked("79927398713"),
+            Ok(Id::new(7992739871))
+        );
+        assert_eq!(Id::<User>::from_str_checked("00"), Ok(Id::new(0)));
+        for padded in ["079927398713", "0079927398713", "000"] {
+            let err = Id::<User, u64>::from_str_checked(padded).unwrap_err();
+            assert_eq!(err.kind(), &ParseIdErrorKind::Malformed, "{padded}");
+        }
+    }
+
+    #[test]
+    fn btree_map_ranges() {
+        let mut posts = TypedIdBTreeMap::<Post, &str, u64>::new();
+        for (id, title) in [(30, "c"), (10, "a"), (40, "d"), (20, "b")] {
+            posts.insert(Id::new(id), title);
+        }
+
+        let keys: Vec<Id<Post, u64>> = posts
+            .range(Id::new(15)..Id::new(40))
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(keys, [Id::new(20), Id::new(30)]);
+
+        let titles: Vec<_> = posts.range(Id::new(30)..).map(|(_, t)| *t).collect();
+        assert_eq!(titles, ["c", "d"]);
+        assert_eq!(posts.first_key_value(), Some((&Id::new(10), &"a")));
+    }
+
+    #[test]
+    fn wrapping_and_saturating_backings() {
+        use std::num::{Saturating, Wrapping};
+
+        let last = Id::<User>::new(u32::MAX);
+
+        let wrapping: Id<User, Wrapping<u32>> = last.into();
+        let wrapped = Id::<User, Wrapping<u32>>::new(wrapping.into_inner() + Wrapping(2));
+        assert_eq!(Id::<User>::from(wrapped), Id::new(1));
+
+        let saturating: Id<User, Saturating<u32>> = last.into();
+        let saturated = Id::<User, Saturating<u32>>::new(saturating.into_inner() + Saturating(2));
+        assert_eq!(Id::<User>::from(saturated), last);
+    }
+
+    #[test]
+    fn write_to_reuses_buffer() {
+        let ids: Vec<Id<User>> = (0..10_000).map(|i| Id::new(i * 7919)).collect();
+
+        let expected: Vec<String> = ids.iter().map(ToString::to_string).collect();
+
+        let mut buf = String::new();
+        for (id, expected) in ids.iter().zip(&expected) {
+            buf.clear();
+            id.write_to(&mut buf).unwrap();
+            assert_eq!(&buf, expected);
+        }
+
+        #[cfg(feature = "itoa")]
+        {
+            let mut bytes = Vec::new();
+            for (id, expected) in ids.iter().zip(&expected) {
+                bytes.clear();
+                id.write_bytes(&mut bytes).unwrap();
+                assert_eq!(bytes, expected.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn interned_string_ids() {
+        use std::sync::Arc;
+
+        let mut interner = IdInterner::<User>::new();
+        let records = ["usr_1", "usr_2", "usr_1", "usr_1"];
+        let ids: Vec<Id<User, Arc<str>>> = records.iter().map(|s| interner.intern(s)).collect();
+
+        assert_eq!(interner.len(), 2);
+        assert!(Arc::ptr_eq(&ids[0].value(), &ids[2].value()));
+        assert!(Arc::ptr_eq(&ids[0].value(), &ids[3].value()));
+        assert!(!Arc::ptr_eq(&ids[0].value(), &ids[1].value()));
+        assert_eq!(ids[1].to_string(), "usr_2");
+    }
+
+    #[test]
+    fn from_byte_arrays() {
+        fn decode<I, const N: usize>(bytes: [u8; N]) -> Id<User, I>
+        where
+            Id<User, I>: From<[u8; N]>,
+        {
+            bytes.into()
         }
+
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(decode::<u32, 4>(bytes), Id::new(u32::from_be_bytes(bytes)));
+        assert_eq!(Id::<Post, i16>::from([0xff, 0xfe]), Id::new(-2));
+        assert_eq!(
+            Id::<Post, u64>::from([0, 0, 0, 0, 0, 0, 1, 0]),
+            Id::new(256)
+        );
+    }
+
+    #[test]
+    fn bitcast_backings() {
+        let all_ones = Id::<User>::new(0xFFFF_FFFF);
+        assert_eq!(all_ones.bitcast_backing::<i32>(), Id::<User, i32>::new(-1));
+        assert_eq!(
+            all_ones.bitcast_backing::<i32>().bitcast_backing::<u32>(),
+            all_ones
+        );
+
+        let min = Id::<Post, i64>::new(i64::MIN);
+        assert_eq!(min.bitcast_backing::<u64>(), Id::<Post, u64>::new(1 << 63));
+        assert_eq!(
+            Id::<Post, i8>::new(5).bitcast_backing::<u8>(),
+            Id::<Post, u8>::new(5)
+        );
+    }
+
+    #[test]
+    fn sentinel_ids() {
+        struct Account;
+        impl Sentinel<u32> for Account {
+            const NONE: u32 = u32::MAX;
+        }
+        impl Sentinel<i64> for Account {
+            const NONE: i64 = -1;
+        }
+
+        let unset = Id::<Account>::none();
+        assert_eq!(unset, Id::new(u32::MAX));
+        assert!(unset.is_none());
+        assert!(!Id::<Account>::default().is_none());
+        assert!(!Id::<Account>::new(7).is_none());
+        assert!(Id::<Account, i64>::new(-1).is_none());
+    }
+
+    #[test]
+    fn parallel_generation_is_unique() {
+        use std::collections::HashSet;
+
+        let generator = AtomicIdGenerator::<User>::new(1);
+        let ids = generator.generate_parallel(1_000_000, 8);
+        assert_eq!(ids.len(), 1_000_000);
+
+        let unique: HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+        assert_eq!(generator.next_id(), Some(Id::new(1_000_001)));
+    }
+
+    #[test]
+    fn atomic_generator_exhaustion() {
+        let generator = AtomicIdGenerator::<User>::new(u64::MAX - 1);
+        assert_eq!(generator.generate_parallel(5, 2).len(), 2);
+        assert_eq!(generator.next_id(), None);
+    }
+
+    #[test]
+    fn optional_id_display() {
+        let assigned = Some(Id::<User>::new(42));
+        let unassigned: Option<Id<User>> = None;
+
+        assert_eq!(display_or(&assigned, "-").to_string(), "42");
+        assert_eq!(display_or(&unassigned, "-").to_string(), "-");
+        assert_eq!(
+            format!(
+                "{:<4}|{:<4}|",
+                display_or(&assigned, "n/a"),
+                display_or(&unassigned, "n/a")
+            ),
+            "42  |n/a |"
+        );
+    }
+
+    #[test]
+    fn thread_ids() {
+        struct Worker;
+
+        let main = Id::<Worker, u64>::current_thread();
+        assert_eq!(Id::current_thread(), main);
+        assert_eq!(Id::from_thread_id(std::thread::current().id()), main);
+
+        let other = std::thread::spawn(|| {
+            let id = Id::<Worker, u64>::current_thread();
+            assert_eq!(Id::current_thread(), id);
+            id
+        })
+        .join()
+        .unwrap();
+        assert_ne!(other, main);
+    }
+
+    #[test]
+    fn user_owns_its_id() {
+        assert_self_id!(User);
+        assert_self_id!(User, u32);
+    }
+
+    #[test]
+    fn scalar_round_trip() {
+        // stand-in for a generated table with a `ulong` field
+        struct PostTable {
+            id: u64,
+        }
+
+        let id = Id::<Post, u64>::new(u64::MAX - 3);
+        let table = PostTable {
+            id: id.into_scalar(),
+        };
+        assert_eq!(table.id, u64::MAX - 3);
+        assert_eq!(Id::<Post, u64>::from_scalar(table.id), id);
+    }
+
+    #[test]
+    fn clamped_values() {
+        let wide = Id::<Post, u64>::new(u64::MAX);
+        assert_eq!(wide.value_as::<u16>(), u16::MAX);
+        assert_eq!(Id::<Post, u64>::new(65_535).value_as::<u16>(), 65_535);
+        assert_eq!(Id::<Post, u64>::new(12).value_as::<u16>(), 12);
+
+        assert_eq!(Id::<Post, i64>::new(i64::MIN).value_as::<i16>(), i16::MIN);
+        assert_eq!(
+            Id::<Post, u128>::new(u128::MAX).value_as::<i128>(),
+            i128::MAX
+        );
+    }
+
+    #[test]
+    fn hex_byte_array_ids() {
+        struct Blob;
+
+        let mut digest = [0u8; 32];
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37);
+        }
+        let id = Id::<Blob, [u8; 32]>::new(digest);
+
+        let hex = id.to_hex();
+        assert_eq!(hex.len(), 64);
+        assert!(hex.starts_with("00254a6f"));
+        assert_eq!(format!("{id:x}"), hex);
+        assert_eq!(format!("{id:>70x}"), format!("      {hex}"));
+        assert_eq!(format!("{id:-<66X}"), format!("{}--", hex.to_uppercase()));
+        assert_eq!(format!("{id:#x}"), format!("0x{hex}"));
+        assert_eq!(Id::from_hex(&hex), Ok(id));
+        let err = Id::<Blob, [u8; 32]>::from_hex(&hex.to_uppercase()).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('A'));
+
+        let err = Id::<Blob, [u8; 32]>::from_hex(&hex[..62]).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::Malformed);
+        let err = Id::<Blob, [u8; 32]>::from_hex(&hex.replace('0', "g")).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::InvalidDigit('g'));
+    }
+
+    #[test]
+    fn widen_and_narrow() {
+        let id = Id::<User, u16>::new(u16::MAX);
+        let wide: Id<User, i64> = id.widen();
+        assert_eq!(wide, Id::new(65_535));
+        assert_eq!(wide.narrow::<u16>(), Ok(id));
+
+        assert!(wide.narrow::<u8>().is_err());
+        assert!(Id::<User, i64>::new(-1).narrow::<u64>().is_err());
+    }
+
+    #[test]
+    fn assert_id_eq_passes() {
+        let id = Id::<User>::new(5);
+        assert_id_eq!(id, Id::new(5));
+        assert_id_eq!(id, Id::new(5), "for {}", "alice");
+        assert_eq!(id.short_owner_type(), "User");
+    }
+
+    #[test]
+    #[should_panic(expected = "UserId mismatch: 5 != 7")]
+    fn assert_id_eq_message() {
+        assert_id_eq!(Id::<User>::new(5), Id::new(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "PostId mismatch: 1 != 2: after import")]
+    fn assert_id_eq_custom_message() {
+        assert_id_eq!(Id::<Post>::new(1), Id::new(2), "after {}", "import");
+    }
+
+    #[test]
+    fn change_whole_collections() {
+        let ids: Vec<Id<User, u32>> = (1..=3).map(Id::new).collect();
+
+        let migrated: Vec<Id<User, u64>> =
+            ids.iter().copied().change_all_backing::<u64>().collect();
+        assert_eq!(migrated, [Id::new(1), Id::new(2), Id::new(3)]);
+
+        let posts: Vec<Id<Post>> = ids.into_iter().change_all_owner::<Post>().collect();
+        assert_eq!(posts[2], Id::new(3));
+    }
+
+    #[test]
+    fn capped_parsing() {
+        const MAX: u64 = 1_000_000;
+
+        assert_eq!(
+            Id::<Post, u64>::from_str_max("1000000", MAX),
+            Ok(Id::new(MAX))
+        );
+        assert_eq!(Id::<Post, u64>::from_str_max("0", MAX), Ok(Id::new(0)));
+
+        let err = Id::<Post, u64>::from_str_max("1000001", MAX).unwrap_err();
+        assert_eq!(err.kind(), &ParseIdErrorKind::AboveMax(MAX.to_string()));
+        assert_eq!(err.input(), "1000001");
+
+        let err = Id::<Post, u64>::from_str_max("99999999999999999999", MAX).unwrap_err();
+        assert!(matches!(err.kind(), ParseIdErrorKind::Backing(_)));
+    }
+
+    #[test]
+    fn id_enum_ordering() {
+        use std::collections::BTreeMap;
+
+        id_enum! {
+            AnyId { User(u32), Post(u64) }
+        }
+
+        let mut names = BTreeMap::new();
+        names.insert(AnyId::from(Id::<Post, u64>::new(1)), "first post");
+        names.insert(AnyId::from(Id::<User>::new(9)), "bob");
+        names.insert(AnyId::from(Id::<Post, u64>::new(0)), "draft");
+        names.insert(AnyId::from(Id::<User>::new(2)), "alice");
+
+        let order: Vec<String> = names.keys().map(ToString::to_string).collect();
+        assert_eq!(order, ["User(2)", "User(9)", "Post(0)", "Post(1)"]);
+    }
+
+    #[test]
+    fn reinterpreted_refs() {
+        let ids = [
+            Id::<User, String>::new("a".to_string()),
+            Id::new("b".to_string()),
+        ];
+
+        let views: Vec<&Id<Post, String>> = ids.iter().map(Id::reinterpret_ref).collect();
+        assert_eq!(views[0].value(), "a");
+        assert_eq!(views[1].value(), ids[1].value());
+        assert!(std::ptr::eq(views[1].reinterpret_ref::<User>(), &ids[1]));
+    }
+
+    #[test]
+    fn qualified_display() {
+        assert_eq!(Id::<User>::new(5).qualified_display(), "UserId(5)");
+        assert_eq!(Id::<Post, i64>::new(-2).qualified_display(), "PostId(-2)");
+        assert_eq!(
+            Id::<Vec<User>, &str>::new("x").qualified_display(),
+            "VecId(x)"
+        );
+    }
+
+    #[test]
+    fn shards() {
+        const SHARDS: u64 = 8;
+        const IDS: u32 = 80_000;
+
+        let id = Id::<User>::new(42);
+        assert_eq!(id.shard(SHARDS), id.shard(SHARDS));
+        assert_eq!(id.shard(1), 0);
+
+        // pinned, as shard placement must never change between hosts or releases
+        assert_eq!(id.shard(SHARDS), 2);
+        assert_eq!(Id::<User, u64>::new(42).shard(1000), 624);
+        assert_eq!(Id::<User, &str>::new("usr_1").shard(1000), 969);
+        assert_eq!(Id::<User, usize>::new(42).shard(1000), 624);
+
+        let mut counts = [0u32; SHARDS as usize];
+        for value in 0..IDS {
+            counts[Id::<User>::new(value).shard(SHARDS) as usize] += 1;
+        }
+        let expected = IDS / SHARDS as u32;
+        for count in counts {
+            assert!(count.abs_diff(expected) < expected / 10, "{counts:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shard_count must be positive")]
+    fn zero_shards() {
+        Id::<User>::new(1).shard(0);
+    }
+
+    #[test]
+    fn message_scalar_fields() {
+        // shaped like prost's generated structs for `uint64 id` & `string author`
+        struct PostMessage {
+            id: u64,
+            author: String,
+        }
+
+        let id = Id::<Post, u64>::new(17);
+        let author = Id::<User, String>::new("usr_9".to_string());
+        let message = PostMessage {
+            id: id.into(),
+            author: author.clone().into(),
+        };
+        assert_eq!(message.id, 17);
+
+        let decoded_id: Id<Post, u64> = message.id.into();
+        let decoded_author: Id<User, String> = message.author.into();
+        assert_eq!(decoded_id, id);
+        assert_eq!(decoded_author, author);
+    }
+
+    #[test]
+    fn display_str_borrows_strings() {
+        use std::borrow::Cow;
+
+        let owned = Id::<User, String>::new("usr_1".to_string());
+        assert!(matches!(owned.as_display_str(), Cow::Borrowed("usr_1")));
+
+        let slice = Id::<User, &str>::new("usr_2");
+        assert!(matches!(slice.as_display_str(), Cow::Borrowed("usr_2")));
+
+        let number = Id::<User>::new(3);
+        assert!(matches!(number.as_display_str(), Cow::Owned(s) if s == "3"));
+    }
+
+    #[test]
+    fn snowflake_clock_regression() {
+        use std::sync::{
+            Arc, Mutex,
+            atomic::{AtomicU64, Ordering},
+        };
+
+        struct TestClock(Arc<AtomicU64>);
+        impl Clock for TestClock {
+            fn now_ms(&self) -> u64 {
+                self.0.load(Ordering::Relaxed)
+            }
+        }
+
+        let time = Arc::new(AtomicU64::new(10_000));
+        let regressions = Arc::new(Mutex::new(Vec::new()));
+        let layout = TimestampLayout::new(0, 41, 12);
+        let mut ids = SnowflakeGenerator::<User, _>::with_clock(layout, TestClock(time.clone()))
+            .on_clock_regression({
+                let regressions = regressions.clone();
+                move |last_ms, now_ms| regressions.lock().unwrap().push((last_ms, now_ms))
+            });
+
+        let first = ids.next_id().unwrap();
+        time.store(9_000, Ordering::Relaxed);
+        let second = ids.next_id().unwrap();
+
+        assert_eq!(*regressions.lock().unwrap(), [(10_000, 9_000)]);
+        assert!(second > first);
+        assert_eq!(second.timestamp_ms(&layout), 10_000);
+
+        time.store(10_001, Ordering::Relaxed);
+        ids.next_id().unwrap();
+        assert_eq!(regressions.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn snowflake_sequence_overflow() {
+        struct FrozenClock;
+        impl Clock for FrozenClock {
+            fn now_ms(&self) -> u64 {
+                500
+            }
+        }
+
+        // 2 bits of sequence allows 4 IDs per millisecond
+        let layout = TimestampLayout::new(0, 20, 2);
+        let mut ids = SnowflakeGenerator::<User, _>::with_clock(layout, FrozenClock);
+        let generated: Vec<_> = (0..6).map(|_| ids.next_id().unwrap()).collect();
+
+        assert!(generated.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(generated[3].timestamp_ms(&layout), 500);
+        assert_eq!(generated[4].timestamp_ms(&layout), 501);
+        assert_eq!(generated[4].sequence(&layout), 0);
+    }
+
+    #[test]
+    fn snowflake_timestamp_exhaustion_never_reissues() {
+        use std::collections::HashSet;
+
+        // 2 timestamp bits end at millisecond 3, & 1 sequence bit allows 2 IDs per millisecond
+        let layout = TimestampLayout::new(0, 2, 1);
+        let mut ids = SnowflakeGenerator::<User, _>::with_clock(layout, MockClock::new(3));
+
+        let mut issued = HashSet::new();
+        for _ in 0..5 {
+            if let Some(id) = ids.next_id() {
+                assert!(issued.insert(id), "{id:?} issued twice");
+            }
+        }
+        assert_eq!(issued.len(), 2);
+        assert_eq!(ids.next_id(), None);
+    }
+
+    #[test]
+    fn snowflake_sequence_overflow_is_not_a_regression() {
+        use std::sync::{Arc, Mutex};
+
+        let clock = MockClock::new(500);
+        let regressions = Arc::new(Mutex::new(Vec::new()));
+        let layout = TimestampLayout::new(0, 20, 2);
+        let mut ids = SnowflakeGenerator::<User, _>::with_clock(layout, clock.clone())
+            .on_clock_regression({
+                let regressions = regressions.clone();
+                move |last_ms, now_ms| regressions.lock().unwrap().push((last_ms, now_ms))
+            });
+
+        // the 5th & 6th IDs borrow timestamp 501 while the clock still reads 500
+        let generated: Vec<_> = (0..6).map(|_| ids.next_id().unwrap()).collect();
+        assert_eq!(generated[5].timestamp_ms(&layout), 501);
+        assert!(regressions.lock().unwrap().is_empty());
+
+        clock.set_ms(499);
+        let after = ids.next_id().unwrap();
+        assert!(after > generated[5]);
+        assert_eq!(*regressions.lock().unwrap(), [(500, 499)]);
+    }
+
+    #[test]
+    fn mock_clock_drives_generators() {
+        use std::time::Duration;
+
+        let clock = MockClock::new(1_700_000_000_000);
+        let layout = TimestampLayout::new(1_600_000_000_000, 41, 12);
+        let mut ids = SnowflakeGenerator::<User, _>::with_clock(layout, clock.clone());
+
+        let first = ids.next_id().unwrap();
+        assert_eq!(first.timestamp_ms(&layout), 1_700_000_000_000);
+        assert_eq!(first.sequence(&layout), 0);
+
+        clock.advance_ms(1_234);
+        let second = ids.next_id().unwrap();
+        assert_eq!(second.timestamp_ms(&layout), 1_700_000_001_234);
+        assert_eq!(first.age(&layout, &clock), Duration::from_millis(1_234));
+        assert_eq!(second.age(&layout, ids.clock()), Duration::ZERO);
+    }
+
+    #[test]
+    fn ip_address_ids() {
+        use std::net::{Ipv4Addr, Ipv6Addr};
+
+        struct Host;
+
+        let ip = Ipv4Addr::new(192, 168, 1, 20);
+        let id: Id<Host, u32> = ip.into();
+        assert_eq!(id, Id::new(u32::from(ip)));
+        assert_eq!(Ipv4Addr::from(id), ip);
+
+        let ip = Ipv6Addr::LOCALHOST;
+        let id: Id<Host, u128> = ip.into();
+        assert_eq!(id, Id::new(1));
+        assert_eq!(Ipv6Addr::from(id), ip);
+    }
+
+    #[test]
+    fn lazily_qualified() {
+        use std::fmt::Write;
+
+        let id = Id::<User>::new(5);
+        assert_eq!(format!("[{}]", id.qualified()), "[UserId(5)]");
+        assert_eq!(id.qualified().to_string(), id.qualified_display());
+
+        let mut out = String::new();
+        write!(out, "{}", Id::<Post>::new(1).qualified()).unwrap();
+        assert_eq!(out, "PostId(1)");
+    }
+
+    #[test]
+    fn nonzero_parsing() {
+        assert_eq!(Id::<User>::from_str_nonzero("1"), Ok(Id::new(1)));
+        assert_eq!(Id::<Post, i32>::from_str_nonzero("-1"), Ok(Id::new(-1)));
+
+        for zero in ["0", "-0", "+0", "000"] {
+            let err = Id::<Post, i32>::from_str_nonzero(zero).unwrap_err();
+            assert_eq!(err.kind(), &ParseIdErrorKind::Zero, "{zero}");
+        }
+        let err = Id::<User>::from_str_nonzero("").unwrap_err();
+        assert!(matches!(err.kind(), ParseIdErrorKind::Backing(_)));
+    }
+
+    #[test]
+    fn into_id_arguments() {
+        fn fetch(users: &[User], id: impl IntoId<User, u32>) -> Option<&User> {
+            let id = id.into_id();
+            users.iter().find(|user| user.id == id)
+        }
+
+        let users = [User {
+            id: Id::new(4),
+            name: "dana".to_string(),
+        }];
+        assert_eq!(fetch(&users, 4u32).unwrap().name, "dana");
+        assert_eq!(fetch(&users, Id::<User>::new(4)), Some(&users[0]));
+        assert_eq!(fetch(&users, 5u32), None);
+    }
+
+    #[test]
+    fn xor_obfuscation() {
+        const USER_KEY: u32 = 0x9e37_79b9;
+
+        for value in [0, 1, 2, 1000, u32::MAX] {
+            let id = Id::<User>::new(value);
+            let public = id.obfuscate(USER_KEY);
+            assert_ne!(public, id);
+            assert_eq!(public.deobfuscate(USER_KEY), id);
+        }
+
+        let neighbours = [Id::<User>::new(41), Id::new(42)].map(|id| id.obfuscate(USER_KEY));
+        assert_ne!(neighbours[0].value().abs_diff(neighbours[1].value()), 1);
+
+        let signed = Id::<Post, i64>::new(-5);
+        assert_eq!(signed.obfuscate(77).deobfuscate(77), signed);
+    }
+
+    #[test]
+    fn formatted_id_round_trips_each_format() {
+        struct Customer;
+        impl IdPrefix for Customer {
+            const PREFIX: &'static str = "cus";
+        }
+
+        fn round_trip<F: IdFormat<Customer, u64>>(value: u64, expected: &str) {
+            let id = Id::<Customer, u64>::new(value).formatted::<F>();
+            assert_eq!(id.to_string(), expected);
+            assert_eq!(expected.parse::<FormattedId<Customer, F, u64>>(), Ok(id));
+        }
+
+        round_trip::<Decimal>(1234, "1234");
+        round_trip::<Hex>(1234, "4d2");
+        round_trip::<Base62>(1234, "Ju");
+        round_trip::<Prefixed>(1234, "cus_Ju");
+        round_trip::<Hex>(u64::MAX, "ffffffffffffffff");
+        round_trip::<Hex>(0, "0");
+        round_trip::<Decimal>(0, "0");
+
+        // only the form Hex writes parses, so each ID has one string form
+        for non_canonical in ["+1234", "01234", "00"] {
+            let err = non_canonical
+                .parse::<FormattedId<Customer, Decimal, u64>>()
+                .unwrap_err();
+            assert_eq!(err.kind(), &ParseIdErrorKind::Malformed, "{non_canonical}");
+        }
+        assert_eq!(
+            "007".parse::<FormattedId<Customer, Decimal, String>>(),
+            Ok(Id::new("007".to_string()).formatted())
+        );
+        for non_canonical in ["04d2", "004d2", "00", "4D2", "FFFFFFFFFFFFFFFF"] {
+            let err = non_canonical
+                .parse::<FormattedId<Customer, Hex, u64>>()
+                .unwrap_err();
+            assert!(
+                matches!(
+                    err.kind(),
+                    ParseIdErrorKind::Malformed | ParseIdErrorKind::InvalidDigit(_)
+                ),
+                "{non_canonical}"
+            );
+        }
+
+        assert!("4g".parse::<FormattedId<Customer, Hex, u64>>().is_err());
+        assert!(
+            "1ffffffffffffffff"
+                .parse::<FormattedId<Customer, Hex, u64>>()
+                .is_err()
+        );
+        assert!(
+            "inv_Ju"
+                .parse::<FormattedId<Customer, Prefixed, u64>>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn from_natural_key_is_stable() {
+        let emails = ["ada@example.com", "grace@example.com", "alan@example.com"];
+        let ids = emails.map(Id::<String, u64>::from_natural_key);
+
+        assert_eq!(
+            ids,
+            emails.map(|email| Id::from_natural_key(String::from(email)))
+        );
+        assert_eq!(
+            ids,
+            emails.map(|email| Id::from_natural_key(email.as_bytes()))
+        );
+        assert_ne!(ids[0], ids[1]);
+        assert_ne!(ids[1], ids[2]);
+        assert_ne!(ids[0], ids[2]);
+
+        // pinned, as persisted keys must never change
+        assert_eq!(ids[0], Id::new(5_842_275_302_017_628_603));
+    }
+
+    #[test]
+    fn desc_key_sorts_newest_first() {
+        let mut ids: Vec<Id<User, u64>> = [5, 12, 1, 7].into_iter().map(Id::new).collect();
+        ids.sort_by_key(Id::desc_key);
+
+        assert_eq!(ids, [12, 7, 5, 1].map(Id::<User, u64>::new));
+        assert!(Id::<User, u64>::new(2).desc_key() < Id::new(1).desc_key());
+    }
+
+    #[test]
+    fn id_alias_registers_canonical_backing() {
+        struct Invoice;
+        id_alias! {
+            /// Invoices are numbered from an external sequence
+            type InvoiceId = Id<Invoice, u64>;
+        }
+
+        let id: IdOf<Invoice> = InvoiceId::new(u64::MAX);
+        assert_eq!(id.value(), u64::MAX);
+        assert_eq!(id.backing_type(), "u64");
+    }
+
+    #[test]
+    fn varint_round_trips() {
+        for (value, len) in [
+            (0, 1),
+            (127, 1),
+            (128, 2),
+            (16_383, 2),
+            (16_384, 3),
+            (u64::MAX, 10),
+        ] {
+            let bytes = Id::<User, u64>::new(value).to_varint();
+            assert_eq!(bytes.len(), len, "{value}");
+            assert_eq!(
+                Id::<User, u64>::from_varint(&bytes),
+                Ok((Id::new(value), len))
+            );
+        }
+
+        let max = Id::<User, u128>::new(u128::MAX).to_varint();
+        assert_eq!(
+            Id::<User, u128>::from_varint(&max),
+            Ok((Id::new(u128::MAX), 19))
+        );
+        assert_eq!(
+            Id::<User, u64>::from_varint(&max),
+            Err(VarintError::Overflow)
+        );
+        assert_eq!(
+            Id::<User, u64>::from_varint(&[]),
+            Err(VarintError::Truncated)
+        );
+
+        // each ID has exactly one encoding
+        for overlong in [&[0x85, 0x00][..], &[0x85, 0x80, 0x80, 0x00], &[0x80, 0x00]] {
+            assert_eq!(
+                Id::<User, u64>::from_varint(overlong),
+                Err(VarintError::Overlong),
+                "{overlong:x?}"
+            );
+        }
+        assert_eq!(
+            Id::<User, u8>::from_varint(&[0x81, 0x81, 0x00]),
+            Err(VarintError::Overflow)
+        );
+        let mut padded = vec![0x80; 10];
+        padded.push(0x01);
+        assert_eq!(
+            Id::<User, u64>::from_varint(&padded),
+            Err(VarintError::Overflow)
+        );
+
+        let mut log = Id::<User, u64>::new(1).to_varint();
+        log.extend(Id::<User, u64>::new(1_000_000).to_varint());
+        let (first, used) = Id::<User, u64>::from_varint(&log).unwrap();
+        let (second, rest) = Id::<User, u64>::from_varint(&log[used..]).unwrap();
+        assert_eq!((first, second), (Id::new(1), Id::new(1_000_000)));
+        assert_eq!(used + rest, log.len());
+    }
+
+    #[test]
+    fn signed_distance_to_spans_full_range() {
+        let start = Id::<User, u64>::new(0);
+        let end = Id::<User, u64>::new(u64::MAX);
+
+        assert_eq!(start.signed_distance_to(&end), i128::from(u64::MAX));
+        assert_eq!(end.signed_distance_to(&start), -i128::from(u64::MAX));
+        assert_eq!(end.signed_distance_to(&end), 0);
+        assert_eq!(
+            Id::<User, i64>::new(i64::MIN).signed_distance_to(&Id::new(i64::MAX)),
+            i128::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn signed_distance_to_panics_beyond_i128() {
+        Id::<User, u128>::new(0).signed_distance_to(&Id::new(u128::MAX));
+    }
+
+    #[test]
+    fn parse_list_reports_bad_index() {
+        let ids = Id::<User, u64>::parse_list("7|8|9", '|').unwrap();
+        assert_eq!(ids, [7, 8, 9].map(Id::<User, u64>::new));
+        assert_eq!(Id::<User, u64>::parse_list("", ','), Ok(Vec::new()));
+
+        let err = Id::<User, u64>::parse_list("1,2,-3,x", ',').unwrap_err();
+        assert_eq!(err.index(), Some(2));
+        assert_eq!(err.input(), "-3");
+        assert_eq!(
+            err.to_string(),
+            r#"invalid tests::tests::User id "-3" at index 2: invalid digit found in string"#
+        );
+        assert_eq!(
+            Id::<User, u64>::parse_list("1,,2", ',')
+                .unwrap_err()
+                .index(),
+            Some(1)
+        );
+        assert_eq!("x".parse::<Id<User>>().unwrap_err().index(), None);
+    }
+
+    #[test]
+    fn type_names_returns_owner_and_backing() {
+        let id = Id::<User, i64>::new(-1);
+        assert_eq!(id.type_names(), ("tests::tests::User", "i64"));
+        assert_eq!(id.type_names(), (id.owner_type(), id.backing_type()));
+    }
+
+    #[test]
+    fn from_url_segment_percent_decodes() {
+        struct Doc;
+        let decode = Id::<Doc, String>::from_url_segment;
+
+        assert_eq!(decode("a%20b"), Ok(Id::new("a b".to_string())));
+        assert_eq!(decode("caf%C3%A9+1"), Ok(Id::new("café+1".to_string())));
+        assert_eq!(decode("100%25"), Ok(Id::new("100%".to_string())));
+        assert_eq!(
+            decode("%zz").unwrap_err().kind(),
+            &ParseIdErrorKind::Malformed
+        );
+        assert_eq!(
+            decode("%C3").unwrap_err().kind(),
+            &ParseIdErrorKind::Malformed
+        );
+        assert!(decode("%+1").is_err());
+        assert_eq!(decode("v1.%2E.2"), Ok(Id::new("v1...2".to_string())));
+
+        // decoded IDs stay a single segment
+        for escaping in [
+            "..%2F..%2Fetc",
+            "a%2fb",
+            "a%00b",
+            "a%0Ab",
+            "a%7F",
+            "%2E%2E",
+            "%2E",
+            "..",
+            "..%5Cetc",
+        ] {
+            assert_eq!(
+                decode(escaping).unwrap_err().kind(),
+                &ParseIdErrorKind::Malformed,
+                "{escaping}"
+            );
+        }
+    }
+
+    #[test]
+    fn clone_into_reuses_target_allocation() {
+        let mut target = Id::<User, String>::new(String::with_capacity(32));
+        let buffer = target.as_display_str().as_ptr();
+
+        for name in ["usr_1", "usr_22", "usr_333"] {
+            let source = Id::<User, String>::new(name.to_string());
+            source.clone_into(&mut target);
+            assert_eq!(target, source);
+            assert_eq!(target.as_display_str().as_ptr(), buffer);
+        }
+    }
+
+    #[test]
+    fn assert_id_size_accepts_backing_size() {
+        assert_id_size!(Id<User, u8>, 1);
+        assert_id_size!(Id<User>, size_of::<u32>());
+        assert_id_size!(Id<User, u128>, 16);
+    }
+
+    #[test]
+    fn composite_id_round_trips() {
+        struct Post;
+        type MembershipKey = CompositeId<(Id<User>, Id<Post, String>)>;
+
+        let key = MembershipKey::new((Id::new(5), Id::new("p:1".to_string())));
+        assert_eq!(key.to_string(), "5:p:1");
+        assert_eq!("5:p:1".parse::<MembershipKey>(), Ok(key.clone()));
+        assert_eq!(key.into_parts().0, Id::new(5));
+
+        let missing = "5".parse::<MembershipKey>().unwrap_err();
+        assert_eq!(missing.kind(), &ParseIdErrorKind::MissingSeparator(':'));
+        let bad_user = "x:p1".parse::<MembershipKey>().unwrap_err();
+        assert_eq!(bad_user.owner_type(), "tests::tests::User");
+    }
+
+    #[test]
+    fn composite_id_escapes_separator_in_first_id() {
+        type Key = CompositeId<(Id<User, String>, Id<User, String>)>;
+
+        for (a, b) in [
+            ("a:b", "c"),
+            ("a", "b:c"),
+            ("100%", ":"),
+            ("%3A", "%"),
+            ("", ""),
+        ] {
+            let key = Key::new((Id::new(a.to_string()), Id::new(b.to_string())));
+            let rendered = key.to_string();
+            assert_eq!(rendered.parse::<Key>(), Ok(key), "{rendered}");
+        }
+        assert_eq!(
+            Key::new((Id::new("a:b".to_string()), Id::new("c".to_string()))).to_string(),
+            "a%3Ab:c"
+        );
+        // only the escapes Display writes are accepted, so each key has one string form
+        for malformed in ["a%zz:c", "a%3ab:c", "a%2:c", "a%:c"] {
+            let err = malformed.parse::<Key>().unwrap_err();
+            assert_eq!(err.kind(), &ParseIdErrorKind::Malformed, "{malformed}");
+        }
+    }
+
+    #[test]
+    fn next_skipping_avoids_blocked_ids() {
+        let retired: std::collections::HashSet<u64> = [2, 3, 7, 13].into();
+        let mut ids = IdGenerator::<User, u64>::new(1);
+
+        let generated: Vec<_> = (0..6).map_while(|_| ids.next_skipping(&retired)).collect();
+        assert_eq!(generated, [1, 4, 5, 6, 8, 9].map(Id::<User, u64>::new));
+        assert!(generated.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let mut last = IdGenerator::<User, u8>::new(254);
+        assert_eq!(last.next_skipping(&[254, 255].into()), None);
+    }
+
+    #[test]
+    fn grouped_display_inserts_commas() {
+        assert_eq!(Id::<User>::new(1_234_567).grouped_display(), "1,234,567");
+        assert_eq!(Id::<User>::new(999).grouped_display(), "999");
+        assert_eq!(Id::<User>::new(0).grouped_display(), "0");
+        assert_eq!(Id::<User, i8>::new(-128).grouped_display(), "-128");
+        assert_eq!(
+            Id::<User, u64>::new(u64::MAX).grouped_display(),
+            "18,446,744,073,709,551,615"
+        );
+        assert_eq!(Id::<User>::new(1_234_567).to_string(), "1234567");
+    }
+
+    #[test]
+    fn eq_opt_compares_optional_backing() {
+        let id = Id::<User, String>::new("usr_1".to_string());
+        let db_value = Some("usr_1".to_string());
+
+        assert!(id.eq_opt(db_value.as_ref()));
+        assert!(!id.eq_opt(Some(&"usr_2".to_string())));
+        assert!(!id.eq_opt(None));
+    }
+
+    #[test]
+    fn secret_id_debug_is_redacted() {
+        let secret = SecretId::from(Id::<User, String>::new("sk_live_4242".to_string()));
+
+        let debug = format!("{secret:?} {secret:#?}");
+        assert!(!debug.contains("4242"), "{debug}");
+        assert_eq!(format!("{secret:?}"), "SecretId<User>([REDACTED])");
+        assert_eq!(secret.expose_secret().as_display_str(), "sk_live_4242");
+    }
+
+    #[test]
+    fn in_rollout_includes_about_percent() {
+        let ids: Vec<Id<User, u64>> = (0..10_000).map(Id::new).collect();
+        let included = |percent| ids.iter().filter(|id| id.in_rollout(percent)).count();
+
+        for percent in [1, 10, 25, 50, 90] {
+            let expected = usize::from(percent) * 100;
+            assert!(included(percent).abs_diff(expected) < 300, "{percent}%");
+        }
+        assert_eq!(included(0), 0);
+        assert_eq!(included(100), ids.len());
+        assert_eq!(included(255), ids.len());
+        assert!(ids.iter().all(|id| !id.in_rollout(10) || id.in_rollout(20)));
+        assert!(ids.iter().all(|id| id.in_rollout(50) == id.in_rollout(50)));
+
+        // pinned, as a user's bucket must never change between services or releases
+        let id = Id::<User, u64>::new(42);
+        assert!(!id.in_rollout(25));
+        assert!(id.in_rollout(26));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct User {
+        id: Id<Self>,
+        name: String,
+    }
+
+    impl HasId for User {
+        fn id(&self) -> Id<Self> {
+            self.id
+        }
+    }
+
+    impl IdPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    struct Post;
+
+    impl IdBacking for Post {
+        type Backing = u64;
     }
 }